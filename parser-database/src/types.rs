@@ -345,9 +345,10 @@ pub(crate) struct ModelAttributes {
 /// ```
 #[bitflags]
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum IndexAlgorithm {
     /// Binary tree index (the default in most databases)
+    #[default]
     BTree,
     /// Hash index
     Hash,
@@ -444,12 +445,6 @@ impl IndexAlgorithm {
     }
 }
 
-impl Default for IndexAlgorithm {
-    fn default() -> Self {
-        Self::BTree
-    }
-}
-
 /// The different types of indexes supported in the Prisma Schema Language.
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 pub enum IndexType {
@@ -1373,20 +1368,15 @@ impl OperatorClassStore {
 }
 
 /// The sort order of an index.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum SortOrder {
     /// ASCending
+    #[default]
     Asc,
     /// DESCending
     Desc,
 }
 
-impl Default for SortOrder {
-    fn default() -> Self {
-        Self::Asc
-    }
-}
-
 /// Prisma's builtin scalar types.
 #[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
 #[allow(missing_docs)]
@@ -0,0 +1,39 @@
+use prismaviz::{diff::SchemaDiff, SourceMap};
+use rocket::serde::{json::Json, Deserialize, Serialize};
+
+use crate::schema_input::SchemaInput;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DiffInput {
+    old: SchemaInput,
+    new: SchemaInput,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct DiffOutput {
+    #[serde(flatten)]
+    diff: SchemaDiff,
+    #[serde(skip_serializing_if = "SourceMap::is_empty")]
+    old_files: SourceMap,
+    #[serde(skip_serializing_if = "SourceMap::is_empty")]
+    new_files: SourceMap,
+}
+
+/**
+ * Compares two schemas, every change carries its spans in the old and the new schema. Spans of
+ * schemas sent as files are offsets into the files concatenated, `old_files` and `new_files` say
+ * where each file sits
+ */
+#[post("/api/v1/diff", data = "<input>")]
+pub fn diff(input: Json<DiffInput>) -> Json<DiffOutput> {
+    let input = input.into_inner();
+    let (old, old_files) = input.old.concat();
+    let (new, new_files) = input.new.concat();
+    Json(DiffOutput {
+        diff: SchemaDiff::new(&old, &new),
+        old_files,
+        new_files,
+    })
+}
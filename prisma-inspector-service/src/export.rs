@@ -0,0 +1,43 @@
+use prismaviz::SchemaDiagnostic;
+use rocket::http::ContentType;
+use rocket::serde::{
+    json::{Json, Value},
+    Deserialize, Serialize,
+};
+
+use crate::schema_input::SchemaInput;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ExportInput {
+    schema: SchemaInput,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct JsonSchemaOutput {
+    json_schema: Option<Value>,
+    diagnostics: Vec<SchemaDiagnostic>,
+}
+
+/**
+ * JSON Schema (draft 2020-12) for the models and composite types, null when the schema has errors
+ */
+#[post("/api/v1/export/jsonschema", data = "<input>")]
+pub fn jsonschema(input: Json<ExportInput>) -> Json<JsonSchemaOutput> {
+    let mut visualiser = input.into_inner().schema.visualiser();
+    let json_schema = visualiser.json_schema();
+    Json(JsonSchemaOutput {
+        json_schema,
+        diagnostics: visualiser.schema_diagnostics(),
+    })
+}
+
+/**
+ * The same standalone SVG diagram `prismaviz --output diagram.svg` writes
+ */
+#[post("/api/v1/export/svg", data = "<input>")]
+pub fn svg(input: Json<ExportInput>) -> (ContentType, String) {
+    let mut visualiser = input.into_inner().schema.visualiser();
+    (ContentType::SVG, visualiser.render_svg())
+}
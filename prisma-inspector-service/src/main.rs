@@ -1,4 +1,8 @@
 mod code_highlight;
+mod diff;
+mod export;
+mod schema_input;
+mod sql;
 mod visualise;
 
 #[macro_use]
@@ -30,8 +34,8 @@ async fn files(file: PathBuf) -> Option<NamedFile> {
 }
 
 #[catch(400)]
-fn bad_request(req: &Request) {
-    format!("Something wrong with the request {}", req.uri());
+fn bad_request(req: &Request) -> String {
+    format!("Something wrong with the request {}", req.uri())
 }
 
 #[shuttle_runtime::main]
@@ -60,10 +64,17 @@ async fn rocket() -> shuttle_rocket::ShuttleRocket {
                 index,
                 visualise::visualise,
                 code_highlight::code_highlight,
+                diff::diff,
+                sql::sql,
+                export::jsonschema,
+                export::svg,
                 files
             ],
         )
         .register("/api/v1/visualise", catchers![bad_request])
+        .register("/api/v1/diff", catchers![bad_request])
+        .register("/api/v1/sql", catchers![bad_request])
+        .register("/api/v1/export", catchers![bad_request])
         .attach(cors);
 
     Ok(rocket.into())
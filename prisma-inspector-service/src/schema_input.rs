@@ -0,0 +1,28 @@
+use prismaviz::{SchemaFile, SchemaVisualiser, SourceMap};
+use rocket::serde::Deserialize;
+
+/// The schema a JSON endpoint works on: the whole schema as a string, or the
+/// `[{ "name": .., "contents": .. }]` files of a schema split over several files, which are parsed
+/// as one like the parts `/api/v1/visualise` takes
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde", untagged)]
+pub enum SchemaInput {
+    Schema(String),
+    Files(Vec<SchemaFile>),
+}
+
+impl SchemaInput {
+    /// The schema, its files concatenated, and where each file sits in it
+    pub fn concat(self) -> (String, SourceMap) {
+        match self {
+            SchemaInput::Schema(schema) => (schema, SourceMap::default()),
+            SchemaInput::Files(files) => SourceMap::concat(&files),
+        }
+    }
+    pub fn visualiser(self) -> SchemaVisualiser {
+        match self {
+            SchemaInput::Schema(schema) => SchemaVisualiser::new(schema),
+            SchemaInput::Files(files) => SchemaVisualiser::from_files(&files),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use prismaviz::{sql::Dialect, SchemaDiagnostic};
+use rocket::serde::{json::Json, Deserialize, Serialize};
+
+use crate::schema_input::SchemaInput;
+
+#[derive(Deserialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SqlInput {
+    schema: SchemaInput,
+    dialect: Dialect,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct SqlOutput {
+    sql: String,
+    diagnostics: Vec<SchemaDiagnostic>,
+}
+
+/**
+ * CREATE statements for the schema in the requested dialect, empty when the schema has errors
+ */
+#[post("/api/v1/sql", data = "<input>")]
+pub fn sql(input: Json<SqlInput>) -> Json<SqlOutput> {
+    let input = input.into_inner();
+    let mut visualiser = input.schema.visualiser();
+    let sql = visualiser.render_sql(input.dialect);
+    Json(SqlOutput {
+        sql,
+        diagnostics: visualiser.schema_diagnostics(),
+    })
+}
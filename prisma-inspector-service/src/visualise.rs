@@ -1,91 +1,56 @@
-use prismaviz::SchemaVisualiser;
+use prismaviz::{SchemaFile, SchemaVisualiser, Selection};
 use rocket::form::Form;
 use rocket::fs::TempFile;
-use rocket::serde::{json::Json, Deserialize, Serialize};
+use rocket::serde::json::Json;
 
 use uuid::Uuid;
 
-use crate::code_highlight;
 #[derive(Debug, FromForm)]
 pub struct VisualiseInput<'r> {
-    schema: TempFile<'r>,
+    /// One part per file of the schema, parsed as one schema
+    schema: Vec<TempFile<'r>>,
 }
 
-#[derive(Serialize, Deserialize)]
-#[serde(crate = "rocket::serde")]
-struct Field {
-    pub name: String,
-    pub r#type: String,
-    pub is_index: String,
-    pub relation_ship_fields: Vec<String>,
-    pub relation_ship_references: Vec<String>,
-    pub constraints: Vec<String>,
-}
-#[derive(Serialize)]
-#[serde(crate = "rocket::serde")]
-struct Model {
-    pub id: String,
-    pub name: String,
-    pub fields: Vec<Field>,
-    pub code: String,
-    pub span: code_highlight::WeakSpan,
-}
-#[derive(Serialize)]
-#[serde(crate = "rocket::serde")]
-pub struct VisualiseOutput {
-    result: Vec<Model>,
-    schema: String,
-}
-
-#[post("/api/v1/visualise", data = "<input>")]
-pub async fn visualise(input: Form<VisualiseInput<'_>>) -> Option<Json<VisualiseOutput>> {
-    let uuid = Uuid::new_v4();
-    let temp_file_path = Ok(std::env::temp_dir().join(uuid.hyphenated().to_string() + ".prisma"));
-    match temp_file_path {
-        Ok(v) => {
-            input
-                .into_inner()
-                .schema
-                .copy_to(&v)
-                .await
-                .expect("Failed to read file contents");
-            let contents = std::fs::read_to_string(&v).unwrap();
-            let mut visualiser = SchemaVisualiser::new(contents.clone());
-            visualiser.parse();
-            let models = visualiser.get_models();
-            let result = models
-                .iter()
-                .map(|m| Model {
-                    id: Uuid::new_v4().hyphenated().to_string(),
-                    name: m.name.clone(),
-                    code: m.code.clone(),
-                    span: code_highlight::WeakSpan {
-                        start: m.span.start,
-                        end: m.span.end,
-                    },
-                    fields: m
-                        .fields
-                        .iter()
-                        .map(|f| Field {
-                            r#type: f.r#type.resolve_with_modifier(),
-                            name: f.name.clone(),
-                            constraints: f.constraints.as_vec(),
-                            relation_ship_fields: f.relation_ships.fields(),
-                            relation_ship_references: f.relation_ships.references(),
-                            is_index: f.is_index.clone(),
-                        })
-                        .collect::<Vec<Field>>(),
-                })
-                .collect::<Vec<Model>>();
-            let _ = std::fs::remove_file::<_>(v);
-            Some(Json(VisualiseOutput {
-                result,
-                schema: contents,
-            }))
-        }
-        Err(e) => {
-            format!("failed to read temp path");
-            e
-        }
+/// `?model=User&depth=2&include=Billing*&exclude=Audit*` focuses the result on a subgraph, the
+/// same way the `--model`, `--depth`, `--include` and `--exclude` flags of the CLI do
+#[post(
+    "/api/v1/visualise?<model>&<depth>&<include>&<exclude>",
+    data = "<input>"
+)]
+pub async fn visualise(
+    input: Form<VisualiseInput<'_>>,
+    model: Vec<String>,
+    depth: Option<usize>,
+    include: Vec<String>,
+    exclude: Vec<String>,
+) -> Option<Json<SchemaVisualiser>> {
+    let mut files = vec![];
+    for (index, mut schema) in input.into_inner().schema.into_iter().enumerate() {
+        // The uploaded name is only used to label spans and diagnostics, never as a path
+        let name = schema
+            .raw_name()
+            .map(|name| name.dangerous_unsafe_unsanitized_raw().to_string())
+            .unwrap_or_else(|| format!("schema-{}.prisma", index + 1));
+        let uuid = Uuid::new_v4();
+        let path = std::env::temp_dir().join(uuid.hyphenated().to_string() + ".prisma");
+        schema
+            .copy_to(&path)
+            .await
+            .expect("Failed to read file contents");
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file::<_>(path);
+        files.push(SchemaFile::new(name, contents));
+    }
+    if files.is_empty() {
+        return None;
     }
+    let mut visualiser = SchemaVisualiser::from_files(&files);
+    visualiser.select(&Selection {
+        models: model,
+        depth: depth.unwrap_or(Selection::default().depth),
+        include,
+        exclude,
+    });
+    // Serialized the way `prismaviz --format json` prints it
+    Some(Json(visualiser))
 }
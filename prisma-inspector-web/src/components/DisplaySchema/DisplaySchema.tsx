@@ -29,16 +29,27 @@ export const DisplaySchema = () => {
   }, [mutationCache]);
 
   return activeSchema ? (
-    <Grid mt={"md"} gutter={"xs"}>
-      {activeSchema.result.map((model) => (
-        <Model
-          activeModel={activeModel}
-          setActiveModel={setActiveModel}
-          model={model}
-          schema={activeSchema.schema}
-        />
+    <>
+      {activeSchema.diagnostics.map((diagnostic) => (
+        <Text
+          key={`${diagnostic.span.start}_${diagnostic.message}`}
+          c={diagnostic.severity === "error" ? "red" : "yellow"}
+          mt={"xs"}
+        >
+          {diagnostic.severity}: {diagnostic.message}
+        </Text>
       ))}
-    </Grid>
+      <Grid mt={"md"} gutter={"xs"}>
+        {activeSchema.result.map((model) => (
+          <Model
+            activeModel={activeModel}
+            setActiveModel={setActiveModel}
+            model={model}
+            schema={activeSchema.schema}
+          />
+        ))}
+      </Grid>
+    </>
   ) : null;
 };
 
@@ -189,6 +200,7 @@ const Model = ({
           <Table.Th>Field name</Table.Th>
           <Table.Th>Type</Table.Th>
           <Table.Th>Constraints</Table.Th>
+          <Table.Th>Relationship</Table.Th>
           <Table.Th>Relationship Field</Table.Th>
           <Table.Th>Relationship Reference</Table.Th>
           <Table.Th>Is_Index</Table.Th>
@@ -212,6 +224,10 @@ const Model = ({
                 <div key={c}>{c}</div>
               ))}
             </Table.Td>
+            <Table.Td>
+              {field.relation_ship_type &&
+                `${field.relation_ship_type} ${field.relation_ship_on}`}
+            </Table.Td>
             <Table.Td style={{ fontWeight: 700 }}>
               {field.relation_ship_fields.map((c) => (
                 <div key={c}>{c}</div>
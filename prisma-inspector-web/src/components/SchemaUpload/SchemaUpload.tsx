@@ -9,7 +9,10 @@ const parseSchemaFile: MutationFunction<unknown, FileWithPath[]> = async (
   files
 ) => {
   let bodyContent = new FormData();
-  bodyContent.append("schema", files[0]);
+  // A schema split over several files is sent as one part per file
+  files.forEach((file) =>
+    bodyContent.append("schema", file, file.path ?? file.name)
+  );
   let headersList = {
     Accept: "*/*",
   };
@@ -32,7 +35,8 @@ export function SchemaUpload(props: Partial<DropzoneProps>) {
     return (
       <FileButton
         accept={"prisma"}
-        onChange={(file) => file && mutateAsync([file])}
+        multiple
+        onChange={(files) => files.length > 0 && mutateAsync(files)}
       >
         {(props) => (
           <Button style={{ marginLeft: "auto" }} {...props}>
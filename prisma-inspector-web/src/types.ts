@@ -2,19 +2,79 @@ export interface SchemaResult {
   result: Array<{
     id: string;
     name: string;
+    kind: "model" | "view" | "enum" | "composite_type";
     span: {
       start: number;
       end: number;
     };
+    file?: string;
     fields: Array<{
       name: string;
       type: string;
+      arity: "required" | "optional" | "list";
       is_index: boolean;
+      database_name: string;
+      native_type: string | null;
+      default: string | null;
       constraints: string[];
       relation_ship_fields: string[];
       relation_ship_references: string[];
+      relation_ship_type: string | null;
+      relation_ship_on: string | null;
     }>;
     code: string;
   }>;
   schema: string;
+  files?: Array<{
+    name: string;
+    start: number;
+    end: number;
+  }>;
+  diagnostics: Array<{
+    message: string;
+    span: {
+      start: number;
+      end: number;
+    };
+    severity: "error" | "warning";
+    file?: string;
+  }>;
+}
+
+export interface DiffResult {
+  changes: Array<{
+    kind: "added" | "removed" | "renamed" | "modified";
+    subject:
+      | "model"
+      | "view"
+      | "enum"
+      | "enum_value"
+      | "field"
+      | "relation"
+      | "index";
+    parent: string;
+    name: string;
+    details: string[];
+    old_span: { start: number; end: number } | null;
+    new_span: { start: number; end: number } | null;
+  }>;
+  old_diagnostics: SchemaResult["diagnostics"];
+  new_diagnostics: SchemaResult["diagnostics"];
+}
+
+export type SqlDialect =
+  | "postgres"
+  | "cockroachdb"
+  | "mysql"
+  | "sqlserver"
+  | "sqlite";
+
+export interface SqlResult {
+  sql: string;
+  diagnostics: SchemaResult["diagnostics"];
+}
+
+export interface JsonSchemaResult {
+  json_schema: Record<string, unknown> | null;
+  diagnostics: SchemaResult["diagnostics"];
 }
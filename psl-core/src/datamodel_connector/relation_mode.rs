@@ -5,10 +5,11 @@ use std::fmt;
 /// Defines the part of the stack where referential actions are handled.
 #[bitflags]
 #[repr(u8)]
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
 pub enum RelationMode {
     /// Enforced in the database. Needs support from the underlying database
     /// server.
+    #[default]
     ForeignKeys,
     /// Enforced in Prisma. Slower, but for databases that do not support
     /// foreign keys.
@@ -32,12 +33,6 @@ impl RelationMode {
     }
 }
 
-impl Default for RelationMode {
-    fn default() -> Self {
-        Self::ForeignKeys
-    }
-}
-
 impl fmt::Display for RelationMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -331,20 +331,18 @@ pub(super) fn validate_scalar_field_connector_specific(
             }
         }
 
-        ScalarFieldType::BuiltInScalar(ScalarType::Decimal) => {
-            if !ctx.connector.supports_decimal() {
-                ctx.push_error(DatamodelError::new_field_validation_error(
-                    &format!(
-                        "Field `{}` in {container} `{}` can't be of type Decimal. The current connector does not support the Decimal type.",
-                        field.name(),
-                        field.model().name(),
-                    ),
-                    container,
-                    field.model().name(),
+        ScalarFieldType::BuiltInScalar(ScalarType::Decimal) if !ctx.connector.supports_decimal() => {
+            ctx.push_error(DatamodelError::new_field_validation_error(
+                &format!(
+                    "Field `{}` in {container} `{}` can't be of type Decimal. The current connector does not support the Decimal type.",
                     field.name(),
-                    field.ast_field().span(),
-                ));
-            }
+                    field.model().name(),
+                ),
+                container,
+                field.model().name(),
+                field.name(),
+                field.ast_field().span(),
+            ));
         }
 
         _ => (),
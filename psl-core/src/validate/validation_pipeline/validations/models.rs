@@ -236,7 +236,7 @@ pub(crate) fn primary_key_connector_specific(model: ModelWalker<'_>, ctx: &mut C
     }
 
     if primary_key.fields().len() > 1 && !ctx.connector.supports_compound_ids() {
-        return ctx.push_error(DatamodelError::new_model_validation_error(
+        ctx.push_error(DatamodelError::new_model_validation_error(
             "The current connector does not support compound ids.",
             container_type,
             model.name(),
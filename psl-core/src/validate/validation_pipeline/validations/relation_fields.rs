@@ -307,17 +307,17 @@ mod tests {
     use super::is_leftwise_included_it;
     #[test]
     fn test_is_left_wise_included() {
-        let item = vec![1, 2];
-        let group = vec![1, 2, 3, 4];
+        let item = [1, 2];
+        let group = [1, 2, 3, 4];
         assert!(is_leftwise_included_it(item.iter(), group.iter()));
-        let item = vec![1, 2, 3, 4];
-        let group = vec![1, 2, 3, 4];
+        let item = [1, 2, 3, 4];
+        let group = [1, 2, 3, 4];
         assert!(is_leftwise_included_it(item.iter(), group.iter()));
-        let item = vec![1, 2, 3, 4];
-        let group = vec![1, 2];
+        let item = [1, 2, 3, 4];
+        let group = [1, 2];
         assert!(!is_leftwise_included_it(item.iter(), group.iter()));
-        let item = vec![2, 3];
-        let group = vec![1, 2, 3, 4];
+        let item = [2, 3];
+        let group = [1, 2, 3, 4];
         assert!(!is_leftwise_included_it(item.iter(), group.iter()));
     }
 }
@@ -354,9 +354,9 @@ impl<'ast> SourcePosition<'ast> {
 pub enum PropertyPosition<'ast> {
     /// prop
     Property,
-    ///
+    /// value
     Value(&'ast str),
-    ///
+    /// function value
     FunctionValue(&'ast str),
 }
 
@@ -1,10 +1,4 @@
-use core::fmt;
-use std::{
-    fmt::{format, Arguments},
-    mem::ManuallyDrop,
-};
-
-use psl_core::schema_ast::ast::{Argument, ArgumentsList, Attribute, Expression};
+use psl_core::parser_database::walkers::ModelWalker;
 
 use crate::constraints::Constraint;
 
@@ -31,44 +25,47 @@ impl Clone for ModelAttributes {
     }
 }
 
+impl Default for ModelAttributes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ModelAttributes {
     pub fn new() -> ModelAttributes {
         ModelAttributes { values: vec![] }
     }
-    pub fn populate(&mut self, model_attributes: &Vec<Attribute>) {
-        model_attributes.iter().for_each(|attr| {
-            attr.arguments
-                .arguments
-                .iter()
-                .for_each(|arg| match &arg.name {
-                    Some(_n) => {}
-                    None => match &arg.value {
-                        Expression::Array(v, _s) => v.iter().for_each(|exp| match exp {
-                            Expression::ConstantValue(v, _s) => {
-                                if attr.name.name == "index" {
-                                    self.values.push(ModelAttributTypes::Index(Index {
-                                        name: String::from(v),
-                                    }));
-                                }
-                                if attr.name.name == "unique" {
-                                    self.values.push(ModelAttributTypes::Unique(Constraint {
-                                        name: String::from(v),
-                                        argument: vec![],
-                                    }));
-                                }
-                                if attr.name.name == "id" {
-                                    self.values.push(ModelAttributTypes::Id(Constraint {
-                                        name: String::from(v),
-                                        argument: vec![],
-                                    }));
-                                }
-                            }
-                            _ => {}
-                        }),
-                        _ => {}
-                    },
-                })
-        })
+    /// Collects the block level `@@id`, `@@unique` and `@@index`/`@@fulltext` attributes of a model
+    pub fn populate(&mut self, model: ModelWalker<'_>) {
+        if let Some(primary_key) = model.primary_key().filter(|pk| !pk.is_defined_on_field()) {
+            primary_key.fields().for_each(|field| {
+                self.values.push(ModelAttributTypes::Id(Constraint {
+                    name: field.name().to_owned(),
+                    argument: vec![],
+                }))
+            });
+        }
+        model
+            .indexes()
+            .filter(|index| !index.is_defined_on_field())
+            .for_each(|index| {
+                // A compound `@@unique` only makes the fields unique together, each one of them
+                // is merely indexed
+                let is_unique = index.is_unique() && index.fields().len() == 1;
+                index
+                    .fields()
+                    .filter_map(|field| field.as_scalar_field())
+                    .for_each(|field| {
+                        let name = field.name().to_owned();
+                        self.values.push(match is_unique {
+                            true => ModelAttributTypes::Unique(Constraint {
+                                name,
+                                argument: vec![],
+                            }),
+                            false => ModelAttributTypes::Index(Index { name }),
+                        })
+                    })
+            });
     }
     pub fn is_index(&self, field: &str) -> &str {
         let index = self.values.iter().find(|x| match x {
@@ -98,195 +95,3 @@ impl ModelAttributes {
             .join("\n")
     }
 }
-
-#[derive(Clone, Copy, Debug)]
-pub enum ArgumentValueKind {
-    Array,
-    Function,
-    Number,
-    StringLiteral,
-    ConstantValue,
-}
-
-impl fmt::Display for ArgumentValueKind {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            ArgumentValueKind::Array => write!(f, "Array"),
-            ArgumentValueKind::Function => write!(f, "Function"),
-            ArgumentValueKind::Number => write!(f, "Number"),
-            ArgumentValueKind::StringLiteral => write!(f, "StringLiteral"),
-            ArgumentValueKind::ConstantValue => write!(f, "ConstantValue"),
-        }
-    }
-}
-
-trait ArgumentMarkup {}
-#[derive(Clone)]
-pub struct CombinedArgument {
-    pub name: String,
-    pub value: String,
-    pub kind: ArgumentValueKind,
-}
-
-impl CombinedArgument {
-    pub fn markup(&self, is_part_of_relational: bool) -> String {
-        let name_markup = if self.name.len() > 0 {
-            if is_part_of_relational {
-                format!(
-                    r#"<span class="argument-name argument-name-is-relational">{}:</span>"#,
-                    self.name
-                )
-            } else {
-                format!(r#"<span class="argument-name">{}:</span>"#, self.name)
-            }
-        } else {
-            "".to_string()
-        };
-        if is_part_of_relational {
-            format!(
-                r#"
-                            <span class="arguments-container">
-                                {}          
-                                <span class="argument-type argument-type-is-relational">{}</span>
-                            </span>
-                    "#,
-                name_markup, self.value
-            )
-        } else {
-            format!(
-                r#"
-                        <span class="arguments-container">
-                            {}          
-                            <span class="argument-type {}">{}</span>
-                        </span>
-                "#,
-                name_markup, self.kind, self.value
-            )
-        }
-    }
-}
-#[derive(Clone)]
-pub struct NonCombinedArgument {
-    pub name: String,
-}
-impl NonCombinedArgument {
-    pub fn markup(&self) -> String {
-        format!(
-            r#"
-                            <span class="argument-name">{}</span>
-                        "#,
-            self.name
-        )
-    }
-}
-
-#[repr(C)]
-pub union PslArgument {
-    pub combined_argument: ManuallyDrop<CombinedArgument>,
-    pub non_combined_argument: ManuallyDrop<NonCombinedArgument>,
-}
-pub struct PslAttribute {
-    pub name: String,
-    pub arguments: Vec<PslArgument>,
-    pub is_relation: bool,
-    pub is_block: bool,
-}
-impl PslAttribute {
-    pub fn new(name: String, is_relation: bool, is_block: bool) -> PslAttribute {
-        PslAttribute {
-            name,
-            arguments: vec![],
-            is_relation,
-            is_block,
-        }
-    }
-    pub fn resolve_arguments(&mut self, args: ArgumentsList) {
-        if args.empty_arguments.len() > 0 {
-            args.empty_arguments.iter().for_each(|empty_arg| {
-                self.arguments.push(PslArgument {
-                    non_combined_argument: ManuallyDrop::new(NonCombinedArgument {
-                        name: empty_arg.name.name.clone(),
-                    }),
-                })
-            })
-        } else if args.arguments.len() > 0 {
-            args.arguments.iter().for_each(|non_empty_arg| {
-                self.arguments.push(PslArgument {
-                    combined_argument: ManuallyDrop::new(CombinedArgument {
-                        name: match &non_empty_arg.name {
-                            Some(n) => n.name.clone(),
-                            None => "".to_string(),
-                        },
-                        value: format!("{}", non_empty_arg.value),
-                        kind: match non_empty_arg.value {
-                            Expression::NumericValue(_, _) => ArgumentValueKind::Number,
-                            Expression::StringValue(_, _) => ArgumentValueKind::StringLiteral,
-                            Expression::ConstantValue(_, _) => ArgumentValueKind::ConstantValue,
-                            Expression::Function(_, _, _) => ArgumentValueKind::Function,
-                            Expression::Array(_, _) => ArgumentValueKind::Array,
-                        },
-                    }),
-                })
-            })
-        }
-    }
-    pub fn attribute_name_markup(&self) -> String {
-        format!(
-            r#"
-                    <span class="attribute-name">
-                        @{}
-                    </span>
-                "#,
-            self.name
-        )
-    }
-    pub fn arguments_markup(&self) -> Vec<String> {
-        self.arguments
-            .iter()
-            .map(|arg| unsafe {
-                match arg {
-                    PslArgument { combined_argument } => combined_argument.markup(self.is_relation),
-                    PslArgument {
-                        non_combined_argument,
-                    } => non_combined_argument.markup(),
-                }
-            })
-            .collect::<Vec<String>>()
-    }
-    pub fn arugments_open(&self) -> String {
-        if self.arguments.len() > 0 {
-            format!(r#"<span class="open-argument">(</span>"#)
-        } else {
-            format!("")
-        }
-    }
-
-    pub fn arugments_close(&self) -> String {
-        if self.arguments.len() > 0 {
-            format!(r#"<span class="close-argument">)</span>"#)
-        } else {
-            format!("")
-        }
-    }
-
-    pub fn attribute_markup(&self) -> String {
-        let attr_name_markup = self.attribute_name_markup();
-        let arguments_markup = self.arguments_markup();
-        let arguments_open = self.arugments_open();
-        let arguments_close = self.arugments_close();
-        format!(
-            r#"
-                <span class="attributes">
-                    {}
-                    {}
-                    {}
-                    {}
-                </span>
-            "#,
-            attr_name_markup,
-            arguments_open,
-            arguments_markup.join(r#"<span>,</span>"#),
-            arguments_close
-        )
-    }
-}
@@ -1,4 +1,7 @@
+use std::fmt;
+
 use psl_core::schema_ast::ast::{Attribute, Expression};
+use serde::{Serialize, Serializer};
 
 #[derive(PartialEq, Clone, Eq, Hash)]
 pub enum ArgumentType {
@@ -13,21 +16,15 @@ pub struct Constraint {
     pub argument: Vec<ArgumentType>,
 }
 
-impl Constraint {
-    pub fn to_string(&self) -> String {
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut arguments = self
             .argument
             .iter()
             .map(|arg| match arg {
-                ArgumentType::Numeric(v) => {
-                    return String::from(v);
-                }
-                ArgumentType::String(v) => {
-                    return String::from(v);
-                }
-                ArgumentType::FunctionCall(v) => {
-                    return String::from(v);
-                }
+                ArgumentType::Numeric(v) => String::from(v),
+                ArgumentType::String(v) => String::from(v),
+                ArgumentType::FunctionCall(v) => String::from(v),
             })
             .collect::<Vec<String>>()
             .join(",");
@@ -39,20 +36,26 @@ impl Constraint {
                 temp
             }
         };
-        return self.name.clone() + &arguments.clone();
+        write!(f, "{}{}", self.name, arguments)
     }
 }
 pub struct Contraints {
     pub constraints: Vec<Constraint>,
 }
 
+impl Default for Contraints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Contraints {
     pub fn new() -> Contraints {
         Contraints {
             constraints: vec![],
         }
     }
-    pub fn populate(&mut self, attributes: &Vec<Attribute>) {
+    pub fn populate(&mut self, attributes: &[Attribute]) {
         attributes.iter().for_each(|a| {
             let attr_name = a.name.name.clone();
             let mut attribute_constraint = Constraint {
@@ -82,20 +85,20 @@ impl Contraints {
                     .for_each(|arg| match &arg.name {
                         Some(v) => {
                             if v.name == "name" {
-                                match &arg.value {
-                                    Expression::StringValue(v, _s) => attribute_constraint
+                                if let Expression::StringValue(v, _s) = &arg.value {
+                                    attribute_constraint
                                         .argument
-                                        .push(ArgumentType::String(v.to_string())),
-                                    _ => {}
+                                        .push(ArgumentType::String(v.to_string()))
                                 }
                             }
                         }
-                        None => match &arg.value {
-                            Expression::StringValue(v, _s) => attribute_constraint
-                                .argument
-                                .push(ArgumentType::String(v.to_string())),
-                            _ => {}
-                        },
+                        None => {
+                            if let Expression::StringValue(v, _s) = &arg.value {
+                                attribute_constraint
+                                    .argument
+                                    .push(ArgumentType::String(v.to_string()))
+                            }
+                        }
                     })
             }
             self.constraints.push(attribute_constraint);
@@ -104,10 +107,18 @@ impl Contraints {
 
     pub fn as_vec(&self) -> Vec<String> {
         let output = self.constraints.iter().map(|c| c.to_string());
-        return output.collect::<Vec<String>>();
+        output.collect::<Vec<String>>()
     }
-    pub fn to_string(&self) -> String {
-        let output = self.constraints.iter().map(|c| c.to_string());
-        return output.collect::<Vec<String>>().join("\n");
+}
+
+impl Serialize for Contraints {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.as_vec())
+    }
+}
+
+impl fmt::Display for Contraints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_vec().join("\n"))
     }
 }
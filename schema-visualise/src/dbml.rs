@@ -0,0 +1,420 @@
+//! DBML for the tables, enums, indexes and references of a validated schema
+use std::fmt::Write;
+
+use psl::{
+    datamodel_connector::walker_ext_traits::*,
+    parser_database::{
+        walkers::{
+            EnumWalker, ImplicitManyToManyRelationWalker, IndexWalker, InlineRelationWalker,
+            ModelWalker, ScalarFieldAttributeWalker, ScalarFieldWalker,
+        },
+        IndexAlgorithm, ReferentialAction, ScalarFieldType, SortOrder,
+    },
+    schema_ast::ast::{self, Expression, WithDocumentation},
+    ValidatedSchema,
+};
+
+use crate::{
+    selection::Kept,
+    sql::{self, Dialect},
+};
+
+/// Bare when DBML allows it, double quoted otherwise
+fn identifier(name: &str) -> String {
+    match name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        true => name.to_string(),
+        false => format!("\"{}\"", name.replace('"', "\\\"")),
+    }
+}
+
+fn qualified(schema: Option<&str>, name: &str) -> String {
+    match schema {
+        Some(schema) => format!("{}.{}", identifier(schema), identifier(name)),
+        None => identifier(name),
+    }
+}
+
+fn table_name(model: ModelWalker<'_>) -> String {
+    qualified(model.schema_name(), model.database_name())
+}
+
+fn enum_name(r#enum: EnumWalker<'_>) -> String {
+    qualified(
+        r#enum.schema().map(|(schema, _)| schema),
+        r#enum.database_name(),
+    )
+}
+
+fn string(value: &str) -> String {
+    format!(
+        "'{}'",
+        value
+            .replace('\\', "\\\\")
+            .replace('\'', "\\'")
+            .replace('\n', "\\n")
+    )
+}
+
+fn columns<'a>(names: impl ExactSizeIterator<Item = &'a str>) -> String {
+    match names.len() {
+        1 => names.map(identifier).collect(),
+        _ => format!("({})", names.map(identifier).collect::<Vec<_>>().join(", ")),
+    }
+}
+
+fn column_type(
+    dialect: Option<Dialect>,
+    schema: &ValidatedSchema,
+    field: ScalarFieldWalker<'_>,
+) -> String {
+    let base = match field.scalar_field_type() {
+        ScalarFieldType::BuiltInScalar(scalar_type) => {
+            match (field.native_type_instance(schema.connector), dialect) {
+                (Some(native_type), _) => {
+                    let (name, args) = schema.connector.native_type_to_parts(&native_type);
+                    sql::native_type_sql(name, &args)
+                }
+                (None, Some(dialect)) => sql::scalar_type_sql(dialect, scalar_type),
+                (None, None) => scalar_type.as_str().to_string(),
+            }
+        }
+        ScalarFieldType::Enum(enum_id) => enum_name(field.db.walk(enum_id)),
+        ScalarFieldType::CompositeType(composite_type_id) => {
+            identifier(field.db.walk(composite_type_id).name())
+        }
+        ScalarFieldType::Unsupported(_) => match &field.ast_field().field_type {
+            ast::FieldType::Unsupported(name, _) => name.clone(),
+            ast::FieldType::Supported(identifier) => identifier.name.clone(),
+        },
+    };
+    let base = match base
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || "_.()[],\"".contains(c))
+    {
+        true => base,
+        false => format!("\"{}\"", base.replace('"', "\\\"")),
+    };
+    match field.is_list() {
+        true => format!("{}[]", base),
+        false => base,
+    }
+}
+
+fn literal(field: ScalarFieldWalker<'_>, value: &Expression) -> Option<String> {
+    match value {
+        Expression::StringValue(value, _) => Some(string(value)),
+        Expression::NumericValue(value, _) => Some(value.clone()),
+        Expression::ConstantValue(value, _) => match field.field_type_as_enum() {
+            Some(r#enum) => r#enum
+                .values()
+                .find(|enum_value| enum_value.name() == value)
+                .map(|enum_value| string(enum_value.database_name())),
+            None => Some(value.clone()),
+        },
+        _ => None,
+    }
+}
+
+/// The `default:` setting, expressions in backticks
+fn default(field: ScalarFieldWalker<'_>) -> Option<String> {
+    let default = field.default_value()?;
+    match default.value() {
+        _ if default.is_autoincrement() || default.is_sequence() => None,
+        Expression::Function(name, arguments, _) if name == "dbgenerated" => arguments
+            .arguments
+            .first()
+            .and_then(|argument| argument.value.as_string_value())
+            .map(|(value, _)| format!("`{}`", value))
+            .filter(|value| value != "``"),
+        Expression::Function(name, arguments, _) => {
+            let arguments: Vec<String> = arguments
+                .arguments
+                .iter()
+                .map(|argument| argument.value.to_string())
+                .collect();
+            Some(format!("`{}({})`", name, arguments.join(", ")))
+        }
+        Expression::Array(_, _) => None,
+        value => literal(field, value),
+    }
+}
+
+/// The attribute can be written as a column setting
+fn is_plain(attribute: ScalarFieldAttributeWalker<'_>) -> bool {
+    attribute.sort_order().is_none() && attribute.length().is_none()
+}
+
+fn is_single_pk(model: ModelWalker<'_>) -> bool {
+    model
+        .primary_key()
+        .is_some_and(|pk| pk.fields().len() == 1 && pk.scalar_field_attributes().all(is_plain))
+}
+
+/// Field level `@unique` without anything a column setting cannot express
+fn is_column_unique(index: IndexWalker<'_>) -> bool {
+    index.is_unique()
+        && index.is_defined_on_field()
+        && index.mapped_name().is_none()
+        && index.scalar_field_attributes().all(is_plain)
+}
+
+fn column(
+    dialect: Option<Dialect>,
+    schema: &ValidatedSchema,
+    field: ScalarFieldWalker<'_>,
+) -> String {
+    let mut settings = vec![];
+    if field.is_single_pk() && is_single_pk(field.model()) {
+        settings.push("pk".to_string());
+    }
+    if field.is_autoincrement() {
+        settings.push("increment".to_string());
+    }
+    let unique = field
+        .model()
+        .indexes()
+        .any(|index| is_column_unique(index) && index.contains_field(field));
+    if unique {
+        settings.push("unique".to_string());
+    }
+    if !field.is_optional() && !field.is_list() {
+        settings.push("not null".to_string());
+    }
+    if let Some(value) = default(field) {
+        settings.push(format!("default: {}", value));
+    }
+    if let Some(documentation) = field.ast_field().documentation() {
+        settings.push(format!("note: {}", string(documentation)));
+    }
+    let mut out = format!(
+        "{} {}",
+        identifier(field.database_name()),
+        column_type(dialect, schema, field)
+    );
+    if !settings.is_empty() {
+        write!(out, " [{}]", settings.join(", ")).unwrap();
+    }
+    out
+}
+
+fn index_column(attribute: ScalarFieldAttributeWalker<'_>) -> &str {
+    let path = attribute.as_mapped_path_to_indexed_field();
+    path.first().map(|(name, _)| *name).unwrap_or_default()
+}
+
+/// Length and sort order of a column the way the schema spells them, e.g. `title(length: 10,
+/// sort: Desc)`. DBML only knows them inside expressions, which it takes for a functional index
+fn column_note(attribute: ScalarFieldAttributeWalker<'_>) -> Option<String> {
+    let mut arguments = vec![];
+    if let Some(length) = attribute.length() {
+        arguments.push(format!("length: {}", length));
+    }
+    match attribute.sort_order() {
+        Some(SortOrder::Asc) => arguments.push("sort: Asc".to_string()),
+        Some(SortOrder::Desc) => arguments.push("sort: Desc".to_string()),
+        None => (),
+    }
+    match arguments.is_empty() {
+        true => None,
+        false => Some(format!(
+            "{}({})",
+            index_column(attribute),
+            arguments.join(", ")
+        )),
+    }
+}
+
+fn index_line<'a>(
+    attributes: impl ExactSizeIterator<Item = ScalarFieldAttributeWalker<'a>>,
+    mut settings: Vec<String>,
+    mut notes: Vec<String>,
+) -> String {
+    let attributes: Vec<ScalarFieldAttributeWalker<'a>> = attributes.collect();
+    let index_columns: Vec<String> = attributes
+        .iter()
+        .map(|attribute| identifier(index_column(*attribute)))
+        .collect();
+    notes.extend(
+        attributes
+            .iter()
+            .filter_map(|attribute| column_note(*attribute)),
+    );
+    if !notes.is_empty() {
+        settings.push(format!("note: {}", string(&notes.join(", "))));
+    }
+    let mut out = match index_columns.len() {
+        1 => index_columns.join(""),
+        _ => format!("({})", index_columns.join(", ")),
+    };
+    if !settings.is_empty() {
+        write!(out, " [{}]", settings.join(", ")).unwrap();
+    }
+    out
+}
+
+fn indexes(model: ModelWalker<'_>) -> Vec<String> {
+    let mut lines = vec![];
+    if let Some(pk) = model.primary_key().filter(|_| !is_single_pk(model)) {
+        let mut settings = vec!["pk".to_string()];
+        if let Some(name) = pk.mapped_name() {
+            settings.push(format!("name: {}", string(name)));
+        }
+        lines.push(index_line(pk.scalar_field_attributes(), settings, vec![]));
+    }
+    model
+        .indexes()
+        .filter(|index| !is_column_unique(*index))
+        .for_each(|index| {
+            let mut settings = vec![];
+            if index.is_unique() {
+                settings.push("unique".to_string());
+            }
+            if index.algorithm() == Some(IndexAlgorithm::Hash) {
+                settings.push("type: hash".to_string());
+            }
+            if let Some(name) = index.mapped_name() {
+                settings.push(format!("name: {}", string(name)));
+            }
+            let notes = match index.is_fulltext() {
+                true => vec!["fulltext".to_string()],
+                false => vec![],
+            };
+            lines.push(index_line(index.scalar_field_attributes(), settings, notes));
+        });
+    lines
+}
+
+fn table(dialect: Option<Dialect>, schema: &ValidatedSchema, model: ModelWalker<'_>) -> String {
+    let mut out = String::new();
+    writeln!(out, "Table {} {{", table_name(model)).unwrap();
+    model.scalar_fields().for_each(|field| {
+        writeln!(out, "  {}", column(dialect, schema, field)).unwrap();
+    });
+    let indexes = indexes(model);
+    if !indexes.is_empty() {
+        out.push_str("\n  indexes {\n");
+        indexes
+            .iter()
+            .for_each(|line| writeln!(out, "    {}", line).unwrap());
+        out.push_str("  }\n");
+    }
+    if let Some(documentation) = model.ast_model().documentation() {
+        writeln!(out, "\n  Note: {}", string(documentation)).unwrap();
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn r#enum(r#enum: EnumWalker<'_>) -> String {
+    let mut out = String::new();
+    writeln!(out, "Enum {} {{", enum_name(r#enum)).unwrap();
+    r#enum.values().for_each(|value| {
+        write!(out, "  {}", identifier(value.database_name())).unwrap();
+        if let Some(documentation) = value.documentation() {
+            write!(out, " [note: {}]", string(documentation)).unwrap();
+        }
+        out.push('\n');
+    });
+    out.push_str("}\n");
+    out
+}
+
+fn referential_action(action: ReferentialAction) -> &'static str {
+    match action {
+        ReferentialAction::Cascade => "cascade",
+        ReferentialAction::Restrict => "restrict",
+        ReferentialAction::NoAction => "no action",
+        ReferentialAction::SetNull => "set null",
+        ReferentialAction::SetDefault => "set default",
+    }
+}
+
+/// `>` from the referencing side, `-` when the foreign key is unique
+fn reference(schema: &ValidatedSchema, relation: InlineRelationWalker<'_>) -> Option<String> {
+    let complete = relation.as_complete()?;
+    let mut out = String::from("Ref");
+    if let Some(name) = relation.mapped_name() {
+        write!(out, " {}", identifier(name)).unwrap();
+    }
+    write!(
+        out,
+        ": {}.{} {} {}.{}",
+        table_name(complete.referencing_model()),
+        columns(
+            complete
+                .referencing_fields()
+                .map(|field| field.database_name())
+        ),
+        match relation.is_one_to_one() {
+            true => "-",
+            false => ">",
+        },
+        table_name(complete.referenced_model()),
+        columns(
+            complete
+                .referenced_fields()
+                .map(|field| field.database_name())
+        ),
+    )
+    .unwrap();
+    if schema.relation_mode().uses_foreign_keys() {
+        write!(
+            out,
+            " [delete: {}, update: {}]",
+            referential_action(complete.on_delete(schema.connector, schema.relation_mode())),
+            referential_action(complete.on_update())
+        )
+        .unwrap();
+    }
+    Some(out)
+}
+
+fn is_between_tables(relation: InlineRelationWalker<'_>) -> bool {
+    !relation.referencing_model().ast_model().is_view()
+        && !relation.referenced_model().ast_model().is_view()
+}
+
+/// `<>` between the primary keys of both sides, DBML leaves the join table implicit too
+fn many_to_many_reference(relation: ImplicitManyToManyRelationWalker<'_>) -> Option<String> {
+    let key = |model: ModelWalker<'_>| {
+        model
+            .primary_key()
+            .map(|pk| columns(pk.fields().map(|field| field.database_name())))
+    };
+    Some(format!(
+        "Ref: {}.{} <> {}.{}",
+        table_name(relation.model_a()),
+        key(relation.model_a())?,
+        table_name(relation.model_b()),
+        key(relation.model_b())?
+    ))
+}
+
+pub(crate) fn render(schema: &ValidatedSchema, kept: &Kept) -> String {
+    let db = &schema.db;
+    let dialect = Dialect::for_connector(schema.connector);
+    let mut blocks = vec![];
+    db.walk_enums()
+        .filter(|walker| kept.r#enum(*walker))
+        .for_each(|walker| blocks.push(r#enum(walker)));
+    db.walk_models()
+        .filter(|model| kept.model(*model))
+        .for_each(|model| blocks.push(table(dialect, schema, model)));
+    let references: Vec<String> = db
+        .walk_relations()
+        .filter(|relation| !relation.is_ignored() && kept.relation(*relation))
+        .filter_map(|relation| {
+            let refined = relation.refine();
+            match (refined.as_inline(), refined.as_many_to_many()) {
+                (Some(inline), _) if is_between_tables(inline) => reference(schema, inline),
+                (_, Some(many_to_many)) => many_to_many_reference(many_to_many),
+                _ => None,
+            }
+        })
+        .map(|line| format!("{}\n", line))
+        .collect();
+    if !references.is_empty() {
+        blocks.push(references.join(""));
+    }
+    blocks.join("\n")
+}
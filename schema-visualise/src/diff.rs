@@ -0,0 +1,784 @@
+//! Structural comparison of two schemas. Declarations, fields and indexes are matched up through
+//! the parser database walkers, so formatting and ordering changes do not show up. Something
+//! counts as renamed when it kept its database name, e.g. through `@map` or `@@map`.
+use std::fmt::{self, Write};
+
+use prettytable::{row, Cell, Row, Table};
+use psl_core::{
+    diagnostics::{Diagnostics, Span},
+    parser_database::{
+        walkers::{
+            EnumValueWalker, EnumWalker, FieldWalker, ModelWalker, RefinedFieldWalker,
+            ScalarFieldAttributeWalker,
+        },
+        ParserDatabase,
+    },
+    schema_ast::{ast, string_literal},
+};
+use serde::{Serialize, Serializer};
+
+use crate::{native_type_string, relations::RelationshipType, serialize_span, validation};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Renamed,
+    Modified,
+}
+
+impl fmt::Display for ChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeKind::Added => write!(f, "added"),
+            ChangeKind::Removed => write!(f, "removed"),
+            ChangeKind::Renamed => write!(f, "renamed"),
+            ChangeKind::Modified => write!(f, "modified"),
+        }
+    }
+}
+
+/// What a `Change` was made to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeSubject {
+    Model,
+    View,
+    Enum,
+    EnumValue,
+    Field,
+    /// A relation field, together with the relation it takes part in
+    Relation,
+    /// `@@id`, `@@unique`, `@@index` and `@@fulltext`
+    Index,
+}
+
+impl fmt::Display for ChangeSubject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChangeSubject::Model => write!(f, "model"),
+            ChangeSubject::View => write!(f, "view"),
+            ChangeSubject::Enum => write!(f, "enum"),
+            ChangeSubject::EnumValue => write!(f, "enum value"),
+            ChangeSubject::Field => write!(f, "field"),
+            ChangeSubject::Relation => write!(f, "relation"),
+            ChangeSubject::Index => write!(f, "index"),
+        }
+    }
+}
+
+fn serialize_optional_span<S: Serializer>(
+    span: &Option<Span>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match span {
+        Some(span) => serialize_span(span, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Change {
+    pub kind: ChangeKind,
+    pub subject: ChangeSubject,
+    /// The model, view or enum the change was made in
+    pub parent: String,
+    /// Name of what changed, the new name when it was renamed
+    pub name: String,
+    /// Modifications as `before -> after`, `added` or `removed`
+    pub details: Vec<String>,
+    #[serde(serialize_with = "serialize_optional_span")]
+    pub old_span: Option<Span>,
+    #[serde(serialize_with = "serialize_optional_span")]
+    pub new_span: Option<Span>,
+}
+
+/// The changes that turn the `old` schema into the `new` one
+#[derive(Serialize)]
+pub struct SchemaDiff {
+    pub changes: Vec<Change>,
+    #[serde(serialize_with = "validation::serialize_diagnostics")]
+    pub old_diagnostics: Diagnostics,
+    #[serde(serialize_with = "validation::serialize_diagnostics")]
+    pub new_diagnostics: Diagnostics,
+}
+
+impl SchemaDiff {
+    /// Compares both schemas, unless either of them fails to validate
+    pub fn new(old: &str, new: &str) -> SchemaDiff {
+        let mut old_schema = psl::validate(old.to_owned().into());
+        let mut new_schema = psl::validate(new.to_owned().into());
+        let mut diff = SchemaDiff {
+            changes: vec![],
+            old_diagnostics: std::mem::take(&mut old_schema.diagnostics),
+            new_diagnostics: std::mem::take(&mut new_schema.diagnostics),
+        };
+        if !diff.has_errors() {
+            diff.diff_models(&old_schema.db, &new_schema.db);
+            diff.diff_enums(&old_schema.db, &new_schema.db);
+        }
+        diff
+    }
+    pub fn has_errors(&self) -> bool {
+        self.old_diagnostics.has_errors() || self.new_diagnostics.has_errors()
+    }
+    /// Why the schemas were not compared, `None` when neither has errors
+    pub fn cannot_diff(&self) -> Option<&'static str> {
+        match (
+            self.old_diagnostics.has_errors(),
+            self.new_diagnostics.has_errors(),
+        ) {
+            (true, true) => Some("Cannot diff: the old and new schemas have errors."),
+            (true, false) => Some("Cannot diff: the old schema has errors."),
+            (false, true) => Some("Cannot diff: the new schema has errors."),
+            (false, false) => None,
+        }
+    }
+    fn push(
+        &mut self,
+        kind: ChangeKind,
+        subject: ChangeSubject,
+        parent: &str,
+        name: &str,
+        details: Vec<String>,
+        spans: (Option<Span>, Option<Span>),
+    ) {
+        self.changes.push(Change {
+            kind,
+            subject,
+            parent: parent.to_owned(),
+            name: name.to_owned(),
+            details,
+            old_span: spans.0,
+            new_span: spans.1,
+        });
+    }
+    fn diff_models(&mut self, old: &ParserDatabase, new: &ParserDatabase) {
+        let pairing = pair(
+            old.walk_models().chain(old.walk_views()),
+            new.walk_models().chain(new.walk_views()),
+            |model| model.name().to_owned(),
+            |old, new| old.database_name() == new.database_name(),
+        );
+        pairing.removed.into_iter().for_each(|model| {
+            let span = Some(model.ast_model().span);
+            let name = model.name();
+            self.push(
+                ChangeKind::Removed,
+                model_subject(model),
+                name,
+                name,
+                vec![],
+                (span, None),
+            );
+        });
+        pairing.added.into_iter().for_each(|model| {
+            let span = Some(model.ast_model().span);
+            let name = model.name();
+            self.push(
+                ChangeKind::Added,
+                model_subject(model),
+                name,
+                name,
+                vec![],
+                (None, span),
+            );
+        });
+        pairing
+            .renamed
+            .into_iter()
+            .chain(pairing.matched)
+            .for_each(|(old, new)| {
+                let mut details = renamed_from(old.name(), new.name());
+                compare(
+                    &mut details,
+                    old.mapped_name()
+                        .map(|name| format!("@@map({})", string_literal(name))),
+                    new.mapped_name()
+                        .map(|name| format!("@@map({})", string_literal(name))),
+                );
+                self.push_modification(
+                    old.name() != new.name(),
+                    model_subject(new),
+                    new.name(),
+                    new.name(),
+                    details,
+                    (old.ast_model().span, new.ast_model().span),
+                );
+                self.diff_fields(old, new);
+                self.diff_indexes(old, new);
+            });
+    }
+    fn diff_fields(&mut self, old_model: ModelWalker<'_>, new_model: ModelWalker<'_>) {
+        let parent = new_model.name();
+        let pairing = pair(
+            old_model.fields(),
+            new_model.fields(),
+            |field| field.name().to_owned(),
+            |old, new| database_name(old).is_some() && database_name(old) == database_name(new),
+        );
+        pairing.removed.into_iter().for_each(|field| {
+            let span = Some(field.ast_field().span);
+            let subject = field_subject(field);
+            self.push(
+                ChangeKind::Removed,
+                subject,
+                parent,
+                field.name(),
+                vec![],
+                (span, None),
+            );
+        });
+        pairing.added.into_iter().for_each(|field| {
+            let span = Some(field.ast_field().span);
+            let subject = field_subject(field);
+            self.push(
+                ChangeKind::Added,
+                subject,
+                parent,
+                field.name(),
+                vec![],
+                (None, span),
+            );
+        });
+        pairing
+            .renamed
+            .into_iter()
+            .chain(pairing.matched)
+            .for_each(|(old, new)| {
+                let mut details = renamed_from(old.name(), new.name());
+                compare(
+                    &mut details,
+                    Some(type_string(old.ast_field())),
+                    Some(type_string(new.ast_field())),
+                );
+                let old_attributes = field_attributes(old);
+                let new_attributes = field_attributes(new);
+                let mut names = old_attributes
+                    .iter()
+                    .chain(new_attributes.iter())
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<_>>();
+                names.sort();
+                names.dedup();
+                names.into_iter().for_each(|name| {
+                    let find = |attributes: &[(&str, String)]| {
+                        attributes
+                            .iter()
+                            .find(|(attribute, _)| *attribute == name)
+                            .map(|(_, rendered)| rendered.clone())
+                    };
+                    compare(&mut details, find(&old_attributes), find(&new_attributes));
+                });
+                compare(&mut details, cardinality(old), cardinality(new));
+                self.push_modification(
+                    old.name() != new.name(),
+                    field_subject(new),
+                    parent,
+                    new.name(),
+                    details,
+                    (old.ast_field().span, new.ast_field().span),
+                );
+            });
+    }
+    fn diff_indexes(&mut self, old_model: ModelWalker<'_>, new_model: ModelWalker<'_>) {
+        let parent = new_model.name();
+        let old_indexes = indexes(old_model);
+        let new_indexes = indexes(new_model);
+        let pairing = pair(
+            old_indexes.iter(),
+            new_indexes.iter(),
+            |index| index.key.clone(),
+            |_, _| false,
+        );
+        pairing.removed.into_iter().for_each(|index| {
+            let spans = (Some(index.span), None);
+            self.push(
+                ChangeKind::Removed,
+                ChangeSubject::Index,
+                parent,
+                &index.key,
+                vec![],
+                spans,
+            );
+        });
+        pairing.added.into_iter().for_each(|index| {
+            let spans = (None, Some(index.span));
+            self.push(
+                ChangeKind::Added,
+                ChangeSubject::Index,
+                parent,
+                &index.key,
+                vec![],
+                spans,
+            );
+        });
+        pairing.matched.into_iter().for_each(|(old, new)| {
+            let mut details = vec![];
+            compare(
+                &mut details,
+                Some(old.rendered.clone()),
+                Some(new.rendered.clone()),
+            );
+            self.push_modification(
+                false,
+                ChangeSubject::Index,
+                parent,
+                &new.key,
+                details,
+                (old.span, new.span),
+            );
+        });
+    }
+    fn diff_enums(&mut self, old: &ParserDatabase, new: &ParserDatabase) {
+        let pairing = pair(
+            old.walk_enums(),
+            new.walk_enums(),
+            |r#enum| r#enum.name().to_owned(),
+            |old, new| old.database_name() == new.database_name(),
+        );
+        pairing.removed.into_iter().for_each(|r#enum| {
+            let span = Some(r#enum.ast_enum().span);
+            let name = r#enum.name();
+            self.push(
+                ChangeKind::Removed,
+                ChangeSubject::Enum,
+                name,
+                name,
+                vec![],
+                (span, None),
+            );
+        });
+        pairing.added.into_iter().for_each(|r#enum| {
+            let span = Some(r#enum.ast_enum().span);
+            let name = r#enum.name();
+            self.push(
+                ChangeKind::Added,
+                ChangeSubject::Enum,
+                name,
+                name,
+                vec![],
+                (None, span),
+            );
+        });
+        pairing
+            .renamed
+            .into_iter()
+            .chain(pairing.matched)
+            .for_each(|(old, new)| {
+                let mut details = renamed_from(old.name(), new.name());
+                compare(
+                    &mut details,
+                    old.mapped_name()
+                        .map(|name| format!("@@map({})", string_literal(name))),
+                    new.mapped_name()
+                        .map(|name| format!("@@map({})", string_literal(name))),
+                );
+                self.push_modification(
+                    old.name() != new.name(),
+                    ChangeSubject::Enum,
+                    new.name(),
+                    new.name(),
+                    details,
+                    (old.ast_enum().span, new.ast_enum().span),
+                );
+                self.diff_enum_values(old, new);
+            });
+    }
+    fn diff_enum_values(&mut self, old_enum: EnumWalker<'_>, new_enum: EnumWalker<'_>) {
+        let parent = new_enum.name();
+        let pairing = pair(
+            old_enum.values(),
+            new_enum.values(),
+            |value| value.name().to_owned(),
+            |old, new| old.database_name() == new.database_name(),
+        );
+        let subject = ChangeSubject::EnumValue;
+        pairing.removed.into_iter().for_each(|value| {
+            let spans = (Some(value_span(value)), None);
+            self.push(
+                ChangeKind::Removed,
+                subject,
+                parent,
+                value.name(),
+                vec![],
+                spans,
+            );
+        });
+        pairing.added.into_iter().for_each(|value| {
+            let spans = (None, Some(value_span(value)));
+            self.push(
+                ChangeKind::Added,
+                subject,
+                parent,
+                value.name(),
+                vec![],
+                spans,
+            );
+        });
+        pairing
+            .renamed
+            .into_iter()
+            .chain(pairing.matched)
+            .for_each(|(old, new)| {
+                let mut details = renamed_from(old.name(), new.name());
+                compare(
+                    &mut details,
+                    old.mapped_name()
+                        .map(|name| format!("@map({})", string_literal(name))),
+                    new.mapped_name()
+                        .map(|name| format!("@map({})", string_literal(name))),
+                );
+                self.push_modification(
+                    old.name() != new.name(),
+                    subject,
+                    parent,
+                    new.name(),
+                    details,
+                    (value_span(old), value_span(new)),
+                );
+            });
+    }
+    /// Records a rename, or a modification when there are any details
+    fn push_modification(
+        &mut self,
+        renamed: bool,
+        subject: ChangeSubject,
+        parent: &str,
+        name: &str,
+        details: Vec<String>,
+        spans: (Span, Span),
+    ) {
+        let kind = match renamed {
+            true => ChangeKind::Renamed,
+            false if details.is_empty() => return,
+            false => ChangeKind::Modified,
+        };
+        self.push(
+            kind,
+            subject,
+            parent,
+            name,
+            details,
+            (Some(spans.0), Some(spans.1)),
+        );
+    }
+    /// Prints the changes as a table, coloured by the kind of change
+    pub fn print_as_table(&self) {
+        let mut table = Table::new();
+        table.add_row(row!["Change", "Subject", "In", "Name", "Details"]);
+        self.changes.iter().for_each(|change| {
+            let style = match change.kind {
+                ChangeKind::Added => "Fg",
+                ChangeKind::Removed => "Fr",
+                ChangeKind::Renamed => "Fc",
+                ChangeKind::Modified => "Fy",
+            };
+            table.add_row(Row::new(vec![
+                Cell::new(&change.kind.to_string()).style_spec(style),
+                Cell::new(&change.subject.to_string()),
+                Cell::new(&change.parent),
+                Cell::new(&change.name),
+                Cell::new(&change.details.join("\n")),
+            ]));
+        });
+        table.printstd();
+    }
+    /// Renders the changes as a markdown table, ready to be posted as a pull request comment. A
+    /// schema with errors is reported instead, with the errors listed
+    pub fn render_markdown(&self) -> String {
+        let mut out = String::from("### Schema changes\n\n");
+        if let Some(reason) = self.cannot_diff() {
+            writeln!(out, "{}\n", reason).unwrap();
+            [
+                ("old", &self.old_diagnostics),
+                ("new", &self.new_diagnostics),
+            ]
+            .into_iter()
+            .flat_map(|(side, diagnostics)| {
+                diagnostics.errors().iter().map(move |error| (side, error))
+            })
+            .for_each(|(side, error)| {
+                writeln!(out, "- {} schema: {}", side, error.message()).unwrap()
+            });
+            return out;
+        }
+        if self.changes.is_empty() {
+            out.push_str("No changes.\n");
+            return out;
+        }
+        out.push_str("| Change | Subject | In | Name | Details |\n");
+        out.push_str("| --- | --- | --- | --- | --- |\n");
+        self.changes.iter().for_each(|change| {
+            writeln!(
+                out,
+                "| {} | {} | `{}` | `{}` | {} |",
+                change.kind,
+                change.subject,
+                change.parent,
+                change.name,
+                change
+                    .details
+                    .iter()
+                    .map(|detail| format!("`{}`", detail.replace('|', "\\|")))
+                    .collect::<Vec<_>>()
+                    .join("<br>")
+            )
+            .unwrap();
+        });
+        out
+    }
+    /// Renders the changes and diagnostics of both schemas as pretty printed JSON
+    pub fn render_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Failed to render JSON.")
+    }
+}
+
+/// Items of the old and new schema, sorted by how they correspond
+struct Pairing<T> {
+    matched: Vec<(T, T)>,
+    renamed: Vec<(T, T)>,
+    removed: Vec<T>,
+    added: Vec<T>,
+}
+
+/// Pairs up items with the same name, then treats the leftovers that are `same` as renames
+fn pair<T: Copy>(
+    old: impl Iterator<Item = T>,
+    new: impl Iterator<Item = T>,
+    name: impl Fn(T) -> String,
+    same: impl Fn(T, T) -> bool,
+) -> Pairing<T> {
+    let mut added = new.collect::<Vec<_>>();
+    let mut pairing = Pairing {
+        matched: vec![],
+        renamed: vec![],
+        removed: vec![],
+        added: vec![],
+    };
+    let mut unmatched = vec![];
+    old.for_each(
+        |old| match added.iter().position(|new| name(*new) == name(old)) {
+            Some(position) => pairing.matched.push((old, added.remove(position))),
+            None => unmatched.push(old),
+        },
+    );
+    unmatched
+        .into_iter()
+        .for_each(|old| match added.iter().position(|new| same(old, *new)) {
+            Some(position) => pairing.renamed.push((old, added.remove(position))),
+            None => pairing.removed.push(old),
+        });
+    pairing.added = added;
+    pairing
+}
+
+fn compare(details: &mut Vec<String>, old: Option<String>, new: Option<String>) {
+    match (old, new) {
+        (Some(old), Some(new)) if old != new => details.push(format!("{old} -> {new}")),
+        (Some(old), None) => details.push(format!("{old} removed")),
+        (None, Some(new)) => details.push(format!("{new} added")),
+        _ => {}
+    }
+}
+
+fn renamed_from(old: &str, new: &str) -> Vec<String> {
+    match old == new {
+        true => vec![],
+        false => vec![format!("renamed from {old}")],
+    }
+}
+
+fn model_subject(model: ModelWalker<'_>) -> ChangeSubject {
+    match model.ast_model().is_view() {
+        true => ChangeSubject::View,
+        false => ChangeSubject::Model,
+    }
+}
+
+fn value_span(value: EnumValueWalker<'_>) -> Span {
+    value.db.walk(value.id.0).ast_enum().values[value.id.1].span
+}
+
+fn field_subject(field: FieldWalker<'_>) -> ChangeSubject {
+    match field.refine() {
+        RefinedFieldWalker::Scalar(_) => ChangeSubject::Field,
+        RefinedFieldWalker::Relation(_) => ChangeSubject::Relation,
+    }
+}
+
+fn database_name(field: FieldWalker<'_>) -> Option<&str> {
+    match field.refine() {
+        RefinedFieldWalker::Scalar(field) => Some(field.database_name()),
+        RefinedFieldWalker::Relation(_) => None,
+    }
+}
+
+/// `Int`, `String?` or `Post[]`
+fn type_string(field: &ast::Field) -> String {
+    let name = match &field.field_type {
+        ast::FieldType::Supported(identifier) => identifier.name.clone(),
+        ast::FieldType::Unsupported(name, _) => format!("Unsupported({})", string_literal(name)),
+    };
+    match field.arity {
+        ast::FieldArity::Required => name,
+        ast::FieldArity::Optional => name + "?",
+        ast::FieldArity::List => name + "[]",
+    }
+}
+
+fn cardinality(field: FieldWalker<'_>) -> Option<String> {
+    match field.refine() {
+        RefinedFieldWalker::Scalar(_) => None,
+        RefinedFieldWalker::Relation(field) => Some(RelationshipType::resolve(field).to_string()),
+    }
+}
+
+/// The field attributes that matter to the database, keyed by attribute name
+fn field_attributes(field: FieldWalker<'_>) -> Vec<(&'static str, String)> {
+    let mut attributes = vec![];
+    match field.refine() {
+        RefinedFieldWalker::Scalar(field) => {
+            let model = field.model();
+            if model
+                .primary_key()
+                .is_some_and(|pk| pk.is_defined_on_field() && pk.fields().any(|f| f == field))
+            {
+                attributes.push(("@id", "@id".to_owned()));
+            }
+            if model.indexes().any(|index| {
+                index.is_unique() && index.is_defined_on_field() && index.contains_field(field)
+            }) {
+                attributes.push(("@unique", "@unique".to_owned()));
+            }
+            if let Some(default) = field.default_value() {
+                attributes.push(("@default", format!("@default({})", default.value())));
+            }
+            if let Some(name) = field.mapped_name() {
+                attributes.push(("@map", format!("@map({})", string_literal(name))));
+            }
+            if let Some(native_type) = field.raw_native_type() {
+                attributes.push(("@db", format!("@{}", native_type_string(native_type))));
+            }
+            if field.is_updated_at() {
+                attributes.push(("@updatedAt", "@updatedAt".to_owned()));
+            }
+            if field.is_ignored() {
+                attributes.push(("@ignore", "@ignore".to_owned()));
+            }
+        }
+        RefinedFieldWalker::Relation(field) => {
+            let mut arguments = vec![];
+            if let Some(name) = field.explicit_relation_name() {
+                arguments.push(string_literal(name).to_string());
+            }
+            if let Some(fields) = field.referencing_fields() {
+                let names = fields.map(|f| f.name()).collect::<Vec<_>>();
+                arguments.push(format!("fields: [{}]", names.join(", ")));
+            }
+            if let Some(references) = field.referenced_fields() {
+                let names = references.map(|f| f.name()).collect::<Vec<_>>();
+                arguments.push(format!("references: [{}]", names.join(", ")));
+            }
+            if let Some(action) = field.explicit_on_delete() {
+                arguments.push(format!("onDelete: {action}"));
+            }
+            if let Some(action) = field.explicit_on_update() {
+                arguments.push(format!("onUpdate: {action}"));
+            }
+            if !arguments.is_empty() {
+                attributes.push(("@relation", format!("@relation({})", arguments.join(", "))));
+            }
+            if field.is_ignored() {
+                attributes.push(("@ignore", "@ignore".to_owned()));
+            }
+        }
+    }
+    attributes
+}
+
+/// A block level index or primary key of a model
+struct IndexDefinition {
+    /// Attribute and field names, e.g. `@@index([a, b])`
+    key: String,
+    /// The whole attribute, including sort orders, lengths and names
+    rendered: String,
+    span: Span,
+}
+
+fn index_field(field: ScalarFieldAttributeWalker<'_>) -> (String, String) {
+    let path = field
+        .as_path_to_indexed_field()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect::<Vec<_>>()
+        .join(".");
+    let mut arguments = vec![];
+    if let Some(sort_order) = field.sort_order() {
+        arguments.push(format!("sort: {sort_order:?}"));
+    }
+    if let Some(length) = field.length() {
+        arguments.push(format!("length: {length}"));
+    }
+    match arguments.is_empty() {
+        true => (path.clone(), path),
+        false => (path.clone(), format!("{path}({})", arguments.join(", "))),
+    }
+}
+
+fn index_definition(
+    attribute: &str,
+    fields: impl Iterator<Item = (String, String)>,
+    mut arguments: Vec<String>,
+    span: Span,
+) -> IndexDefinition {
+    let (names, rendered): (Vec<_>, Vec<_>) = fields.unzip();
+    arguments.insert(0, format!("[{}]", rendered.join(", ")));
+    IndexDefinition {
+        key: format!("{attribute}([{}])", names.join(", ")),
+        rendered: format!("{attribute}({})", arguments.join(", ")),
+        span,
+    }
+}
+
+fn indexes(model: ModelWalker<'_>) -> Vec<IndexDefinition> {
+    let mut indexes = vec![];
+    if let Some(pk) = model.primary_key().filter(|pk| !pk.is_defined_on_field()) {
+        let mut arguments = vec![];
+        if let Some(name) = pk.name() {
+            arguments.push(format!("name: {}", string_literal(name)));
+        }
+        if let Some(name) = pk.mapped_name() {
+            arguments.push(format!("map: {}", string_literal(name)));
+        }
+        indexes.push(index_definition(
+            "@@id",
+            pk.scalar_field_attributes().map(index_field),
+            arguments,
+            pk.ast_attribute().span,
+        ));
+    }
+    model
+        .indexes()
+        .filter(|index| !index.is_defined_on_field())
+        .for_each(|index| {
+            let mut arguments = vec![];
+            if let Some(name) = index.name() {
+                arguments.push(format!("name: {}", string_literal(name)));
+            }
+            if let Some(name) = index.mapped_name() {
+                arguments.push(format!("map: {}", string_literal(name)));
+            }
+            if let Some(algorithm) = index.algorithm() {
+                arguments.push(format!("type: {algorithm:?}"));
+            }
+            indexes.push(index_definition(
+                index.attribute_name(),
+                index.scalar_field_attributes().map(index_field),
+                arguments,
+                index.ast_attribute().span,
+            ));
+        });
+    indexes
+}
@@ -0,0 +1,246 @@
+//! Markdown data dictionary of the models, views and enums of a schema, doc comments included
+use std::fmt::Write;
+
+use psl::{
+    parser_database::{
+        walkers::{EnumWalker, ModelWalker, RelationFieldWalker, ScalarFieldWalker},
+        ParserDatabase,
+    },
+    schema_ast::ast::{self, WithDocumentation},
+};
+
+use crate::selection::Kept;
+
+/// Keeps a value inside one cell of a Markdown table
+fn cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', "<br>")
+}
+
+fn code(value: &str) -> String {
+    format!("`{}`", value.replace('|', "\\|"))
+}
+
+/// GitHub's anchor for a heading
+fn anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || *c == '-' || *c == '_' || *c == ' ')
+        .map(|c| if c == ' ' { '-' } else { c })
+        .collect()
+}
+
+fn link(name: &str) -> String {
+    format!("[`{}`](#{})", name, anchor(name))
+}
+
+fn render_documentation(out: &mut String, documentation: Option<&str>) {
+    if let Some(documentation) = documentation {
+        writeln!(out, "{}\n", documentation).unwrap();
+    }
+}
+
+fn field_type(field: ScalarFieldWalker<'_>) -> String {
+    let ast_field = field.ast_field();
+    let mut type_name = match &ast_field.field_type {
+        ast::FieldType::Supported(identifier) => identifier.name.clone(),
+        ast::FieldType::Unsupported(name, _) => format!("Unsupported(\"{}\")", name),
+    };
+    if ast_field.arity.is_list() {
+        type_name.push_str("[]");
+    }
+    if let Some((prefix, name, args, _)) = field.raw_native_type() {
+        write!(type_name, " @{}.{}", prefix, name).unwrap();
+        if !args.is_empty() {
+            write!(type_name, "({})", args.join(", ")).unwrap();
+        }
+    }
+    code(&type_name)
+}
+
+fn constraints(kept: &Kept, field: ScalarFieldWalker<'_>) -> Vec<&'static str> {
+    let model = field.model();
+    let mut constraints = vec![];
+    if field.is_single_pk() || field.is_part_of_a_compound_pk() {
+        constraints.push("PK");
+    } else if field.is_unique() {
+        constraints.push("Unique");
+    }
+    let is_foreign_key = model.relation_fields().any(|relation_field| {
+        kept.relation_field(relation_field)
+            && relation_field
+                .fields()
+                .is_some_and(|mut fields| fields.any(|f| f.field_id() == field.field_id()))
+    });
+    if is_foreign_key {
+        constraints.push("FK");
+    }
+    if model
+        .indexes()
+        .any(|index| !index.is_unique() && index.contains_field(field))
+    {
+        constraints.push("Indexed");
+    }
+    if field.is_updated_at() {
+        constraints.push("Updated at");
+    }
+    if field.is_ignored() {
+        constraints.push("Ignored");
+    }
+    constraints
+}
+
+fn render_fields(out: &mut String, kept: &Kept, model: ModelWalker<'_>) {
+    out.push_str("| Field | Type | Nullable | Default | Constraints | Column | Description |\n");
+    out.push_str("| --- | --- | --- | --- | --- | --- | --- |\n");
+    model.scalar_fields().for_each(|field| {
+        let default = field
+            .default_value()
+            .map(|default| code(&default.value().to_string()))
+            .unwrap_or_default();
+        writeln!(
+            out,
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            code(field.name()),
+            field_type(field),
+            match field.is_optional() {
+                true => "Yes",
+                false => "No",
+            },
+            default,
+            constraints(kept, field).join(", "),
+            code(field.database_name()),
+            cell(field.ast_field().documentation().unwrap_or_default())
+        )
+        .unwrap();
+    });
+}
+
+/// `one-to-many` and friends, seen from the side of `field`
+fn cardinality(field: RelationFieldWalker<'_>) -> &'static str {
+    let this_side = field.ast_field().arity.is_list();
+    let other_side = field
+        .opposite_relation_field()
+        .is_some_and(|opposite| opposite.ast_field().arity.is_list());
+    match (other_side, this_side) {
+        (false, false) => "one-to-one",
+        (false, true) => "one-to-many",
+        (true, false) => "many-to-one",
+        (true, true) => "many-to-many",
+    }
+}
+
+fn render_relations(out: &mut String, kept: &Kept, model: ModelWalker<'_>) {
+    let relation_fields: Vec<RelationFieldWalker<'_>> = model
+        .relation_fields()
+        .filter(|field| kept.relation_field(*field))
+        .collect();
+    if relation_fields.is_empty() {
+        return;
+    }
+    out.push_str("\n**Relations**\n\n");
+    relation_fields.iter().for_each(|field| {
+        write!(
+            out,
+            "- {}: {} {}",
+            code(field.name()),
+            cardinality(*field),
+            link(field.related_model().name())
+        )
+        .unwrap();
+        if let (Some(fields), Some(references)) = (field.fields(), field.referenced_fields()) {
+            let fields: Vec<&str> = fields.map(|field| field.name()).collect();
+            let references: Vec<&str> = references.map(|field| field.name()).collect();
+            write!(
+                out,
+                " through {} referencing {}",
+                code(&fields.join(", ")),
+                code(&references.join(", "))
+            )
+            .unwrap();
+        }
+        if let Some(on_delete) = field.explicit_on_delete() {
+            write!(out, ", on delete {}", on_delete.as_str()).unwrap();
+        }
+        if let Some(on_update) = field.explicit_on_update() {
+            write!(out, ", on update {}", on_update.as_str()).unwrap();
+        }
+        if let Some(documentation) = field.ast_field().documentation() {
+            write!(out, " — {}", documentation.replace('\n', " ")).unwrap();
+        }
+        out.push('\n');
+    });
+}
+
+fn render_model(out: &mut String, kept: &Kept, model: ModelWalker<'_>) {
+    writeln!(out, "### {}\n", model.name()).unwrap();
+    render_documentation(out, model.ast_model().documentation());
+    let kind = match model.ast_model().is_view() {
+        true => "View",
+        false => "Table",
+    };
+    write!(out, "{}: {}", kind, code(model.database_name())).unwrap();
+    if let Some(schema) = model.schema_name() {
+        write!(out, " in schema {}", code(schema)).unwrap();
+    }
+    if model.is_ignored() {
+        out.push_str(", ignored by the Prisma Client");
+    }
+    out.push_str("\n\n");
+    render_fields(out, kept, model);
+    render_relations(out, kept, model);
+}
+
+fn render_enum(out: &mut String, r#enum: EnumWalker<'_>) {
+    writeln!(out, "### {}\n", r#enum.name()).unwrap();
+    render_documentation(out, r#enum.ast_enum().documentation());
+    if r#enum.mapped_name().is_some() {
+        writeln!(out, "Type: {}\n", code(r#enum.database_name())).unwrap();
+    }
+    out.push_str("| Value | Database value | Description |\n");
+    out.push_str("| --- | --- | --- |\n");
+    r#enum.values().for_each(|value| {
+        writeln!(
+            out,
+            "| {} | {} | {} |",
+            code(value.name()),
+            code(value.database_name()),
+            cell(value.documentation().unwrap_or_default())
+        )
+        .unwrap();
+    });
+}
+
+pub(crate) fn render(db: &ParserDatabase, kept: &Kept) -> String {
+    let mut sections = vec![String::from("# Data dictionary\n")];
+    let mut section = |title: &str, blocks: Vec<String>| {
+        if !blocks.is_empty() {
+            sections.push(format!("## {}\n", title));
+            sections.extend(blocks);
+        }
+    };
+    let render_models = |models: &mut dyn Iterator<Item = ModelWalker<'_>>| {
+        models
+            .filter(|model| kept.model(*model))
+            .map(|model| {
+                let mut out = String::new();
+                render_model(&mut out, kept, model);
+                out
+            })
+            .collect::<Vec<_>>()
+    };
+    section("Models", render_models(&mut db.walk_models()));
+    section("Views", render_models(&mut db.walk_views()));
+    section(
+        "Enums",
+        db.walk_enums()
+            .filter(|r#enum| kept.r#enum(*r#enum))
+            .map(|r#enum| {
+                let mut out = String::new();
+                render_enum(&mut out, r#enum);
+                out
+            })
+            .collect(),
+    );
+    sections.join("\n")
+}
@@ -0,0 +1,73 @@
+use std::fmt::Write;
+
+use crate::{PrismaVizModel, PrismaVizModelField};
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_row(out: &mut String, model: &PrismaVizModel, field: &PrismaVizModelField) {
+    writeln!(
+        out,
+        r#"            <TR><TD PORT="{}" ALIGN="LEFT">{}</TD><TD ALIGN="LEFT">{}</TD><TD ALIGN="LEFT">{}</TD></TR>"#,
+        escape(&field.name),
+        escape(&field.name),
+        escape(&field.r#type.resolve_with_modifier()),
+        model.key_markers(field).join(", ")
+    )
+    .unwrap();
+}
+
+fn render_node(out: &mut String, model: &PrismaVizModel) {
+    writeln!(out, "    \"{}\" [label=<", model.name).unwrap();
+    out.push_str(
+        "        <TABLE BORDER=\"0\" CELLBORDER=\"1\" CELLSPACING=\"0\" CELLPADDING=\"4\">\n",
+    );
+    writeln!(
+        out,
+        r#"            <TR><TD COLSPAN="3" BGCOLOR="lightgrey"><B>{}</B></TD></TR>"#,
+        escape(&model.name)
+    )
+    .unwrap();
+    model
+        .fields
+        .iter()
+        .for_each(|field| render_row(out, model, field));
+    out.push_str("        </TABLE>\n    >];\n");
+}
+
+fn render_edges(out: &mut String, model: &PrismaVizModel) {
+    model.fields.iter().for_each(|field| {
+        let target = field.r#type.get_data_type();
+        field
+            .relation_ships
+            .fields()
+            .iter()
+            .zip(field.relation_ships.references().iter())
+            .for_each(|(from, to)| {
+                writeln!(
+                    out,
+                    "    \"{}\":\"{}\" -> \"{}\":\"{}\" [label=\"{}\"];",
+                    model.name, from, target, to, field.name
+                )
+                .unwrap();
+            });
+    });
+}
+
+pub(crate) fn render(models: &[PrismaVizModel]) -> String {
+    let mut out = String::from("digraph schema {\n");
+    out.push_str("    graph [rankdir=LR];\n");
+    out.push_str("    node [shape=plaintext];\n");
+    let models = models.iter().filter(|m| m.kind.is_model_like());
+    models
+        .clone()
+        .for_each(|model| render_node(&mut out, model));
+    models.for_each(|model| render_edges(&mut out, model));
+    out.push_str("}\n");
+    out
+}
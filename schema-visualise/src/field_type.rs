@@ -1,14 +1,6 @@
 use std::fmt::Display;
 
-use psl_core::{
-    parser_database::{
-        walkers::{RefinedFieldWalker, Walker},
-        ScalarType,
-    },
-    schema_ast::ast::{FieldArity, FieldId, ModelId},
-};
-
-use crate::attributes::{PslArgument, PslAttribute};
+use serde::{Serialize, Serializer};
 
 #[derive(Clone, Debug)]
 pub enum DataTypes {
@@ -44,6 +36,18 @@ impl Display for PrismaVizFieldType {
     }
 }
 
+impl Serialize for PrismaVizFieldType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.resolve_with_modifier())
+    }
+}
+
+impl Default for PrismaVizFieldType {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PrismaVizFieldType {
     pub fn new() -> PrismaVizFieldType {
         PrismaVizFieldType {
@@ -51,6 +55,13 @@ impl PrismaVizFieldType {
             modifier: "".to_string(),
         }
     }
+    /// Enum values carry no data type of their own
+    pub fn enum_value() -> PrismaVizFieldType {
+        PrismaVizFieldType {
+            data_type: DataTypes::Unknown("".to_string()),
+            modifier: "".to_string(),
+        }
+    }
     pub fn resolve_data_type(&mut self, name: String, modifier: String) {
         self.modifier = modifier;
         self.data_type = match name.as_str() {
@@ -64,6 +75,7 @@ impl PrismaVizFieldType {
             "Json" => DataTypes::Json(name.clone()),
             "Bytes" => DataTypes::Bytes(name.clone()),
             "Unsupported" => DataTypes::Unsupported(name.clone()),
+            n if n.starts_with("Unsupported(") => DataTypes::Unsupported(name.clone()),
             _ => DataTypes::Relational(name.clone()),
         };
     }
@@ -87,100 +99,3 @@ impl PrismaVizFieldType {
         self.get_data_type().to_owned() + &self.modifier.to_owned()
     }
 }
-
-pub struct PslField {
-    pub name: String,
-    pub field_type: String,
-    pub modifier: String,
-    pub attributes: Vec<PslAttribute>,
-    pub is_relational: bool,
-}
-
-impl PslField {
-    pub fn new(name: String) -> PslField {
-        return PslField {
-            name,
-            field_type: "".to_string(),
-            modifier: String::from(""),
-            attributes: vec![],
-            is_relational: false,
-        };
-    }
-
-    pub fn resolve_field(&mut self, field: Walker<'_, (ModelId, FieldId)>) {
-        let refined_field_type = field.refine();
-        self.modifier = match field.ast_field().arity {
-            FieldArity::Required => "".to_owned(),
-            FieldArity::Optional => "?".to_owned(),
-            FieldArity::List => "[]".to_owned(),
-        };
-        match refined_field_type {
-            RefinedFieldWalker::Scalar(f) => {
-                self.field_type = match f.scalar_type() {
-                    Some(v) => match v {
-                        ScalarType::Int => "Int".to_string(),
-                        ScalarType::BigInt => "BigInt".to_string(),
-                        ScalarType::Float => "Float".to_string(),
-                        ScalarType::Boolean => "Boolean".to_string(),
-                        ScalarType::String => "String".to_string(),
-                        ScalarType::DateTime => "DateTime".to_string(),
-                        ScalarType::Json => "Json".to_string(),
-                        ScalarType::Bytes => "Bytes".to_string(),
-                        ScalarType::Decimal => "Decimal".to_string(),
-                    },
-                    None => {
-                        println!("Looks like field type is not resolvable");
-                        "".to_string()
-                    }
-                };
-                // Start filling the attributes
-                f.ast_field()
-                    .attributes
-                    .clone()
-                    .into_iter()
-                    .for_each(|attr| {
-                        let mut attribute = PslAttribute::new(attr.name.name, false, false);
-                        attribute.resolve_arguments(attr.arguments);
-                        self.attributes.push(attribute)
-                    })
-            }
-            RefinedFieldWalker::Relation(f) => {
-                self.field_type = f.name().to_string();
-                self.is_relational = true;
-                f.ast_field()
-                    .attributes
-                    .clone()
-                    .into_iter()
-                    .for_each(|attr| {
-                        let mut attribute = PslAttribute::new(attr.name.name, true, false);
-                        attribute.resolve_arguments(attr.arguments);
-                        self.attributes.push(attribute)
-                    })
-            }
-        }
-    }
-
-    pub fn resolve_field_name_markup(&self) -> String {
-        format!(r#"<span class="field-name">{}</span>"#, self.name)
-    }
-
-    pub fn resolve_field_type_markup(&self) -> String {
-        format!(
-            r#"
-                    <span class="field-type">
-                        {}
-                        <span class="field-modifier">
-                            {}
-                        </span>
-                    </span>"#,
-            self.field_type, self.modifier
-        )
-    }
-
-    pub fn resolve_attributes_markup(&self) -> Vec<String> {
-        self.attributes
-            .iter()
-            .map(|attr| attr.attribute_markup())
-            .collect::<Vec<String>>()
-    }
-}
@@ -0,0 +1,174 @@
+//! GraphQL type definitions for the models, views, enums and composite types of a schema
+use std::{collections::BTreeSet, fmt::Write};
+
+use psl::{
+    parser_database::{
+        walkers::{ModelWalker, RefinedFieldWalker},
+        ParserDatabase, ScalarFieldType, ScalarType,
+    },
+    schema_ast::ast::{FieldArity, WithDocumentation},
+};
+
+use crate::selection::Kept;
+
+/// Built in scalars keep their GraphQL name, the others are declared as custom scalars
+fn scalar(scalar_type: ScalarType) -> (&'static str, bool) {
+    match scalar_type {
+        ScalarType::Int => ("Int", false),
+        ScalarType::Float => ("Float", false),
+        ScalarType::String => ("String", false),
+        ScalarType::Boolean => ("Boolean", false),
+        ScalarType::BigInt => ("BigInt", true),
+        ScalarType::DateTime => ("DateTime", true),
+        ScalarType::Decimal => ("Decimal", true),
+        ScalarType::Json => ("Json", true),
+        ScalarType::Bytes => ("Bytes", true),
+    }
+}
+
+fn render_documentation(out: &mut String, documentation: Option<&str>, indent: &str) {
+    if let Some(documentation) = documentation {
+        writeln!(out, "{}\"\"\"", indent).unwrap();
+        documentation
+            .lines()
+            .for_each(|line| writeln!(out, "{}{}", indent, line).unwrap());
+        writeln!(out, "{}\"\"\"", indent).unwrap();
+    }
+}
+
+/// `T`, `T!` or `[T!]!`
+fn with_arity(type_name: &str, arity: FieldArity) -> String {
+    match arity {
+        FieldArity::Required => format!("{}!", type_name),
+        FieldArity::Optional => type_name.to_string(),
+        FieldArity::List => format!("[{}!]!", type_name),
+    }
+}
+
+struct Renderer<'db> {
+    db: &'db ParserDatabase,
+    kept: &'db Kept,
+    /// Custom scalars the rendered fields use
+    scalars: BTreeSet<&'static str>,
+}
+
+impl<'db> Renderer<'db> {
+    fn field_type(&mut self, r#type: ScalarFieldType) -> Option<&'db str> {
+        match r#type {
+            ScalarFieldType::BuiltInScalar(scalar_type) => {
+                let (name, custom) = scalar(scalar_type);
+                if custom {
+                    self.scalars.insert(name);
+                }
+                Some(name)
+            }
+            ScalarFieldType::Enum(enum_id) => Some(self.db.walk(enum_id).name()),
+            ScalarFieldType::CompositeType(composite_type_id) => {
+                Some(self.db.walk(composite_type_id).name())
+            }
+            ScalarFieldType::Unsupported(_) => None,
+        }
+    }
+
+    fn render_model(&mut self, out: &mut String, model: ModelWalker<'db>) {
+        render_documentation(out, model.ast_model().documentation(), "");
+        writeln!(out, "type {} {{", model.name()).unwrap();
+        model.fields().for_each(|field| {
+            let line = match field.refine() {
+                RefinedFieldWalker::Scalar(scalar_field) if !scalar_field.is_ignored() => self
+                    .field_type(scalar_field.scalar_field_type())
+                    .map(|type_name| {
+                        format!(
+                            "{}: {}",
+                            scalar_field.name(),
+                            with_arity(type_name, scalar_field.ast_field().arity)
+                        )
+                    }),
+                RefinedFieldWalker::Relation(relation_field)
+                    if !relation_field.is_ignored()
+                        && !relation_field.related_model().is_ignored()
+                        && self.kept.relation_field(relation_field) =>
+                {
+                    Some(format!(
+                        "{}: {}",
+                        relation_field.name(),
+                        with_arity(
+                            relation_field.related_model().name(),
+                            relation_field.ast_field().arity
+                        )
+                    ))
+                }
+                _ => None,
+            };
+            if let Some(line) = line {
+                render_documentation(out, field.ast_field().documentation(), "  ");
+                writeln!(out, "  {}", line).unwrap();
+            }
+        });
+        out.push_str("}\n");
+    }
+}
+
+pub(crate) fn render<'db>(db: &'db ParserDatabase, kept: &'db Kept) -> String {
+    let mut renderer = Renderer {
+        db,
+        kept,
+        scalars: BTreeSet::new(),
+    };
+    let mut definitions = vec![];
+    db.walk_enums()
+        .filter(|r#enum| kept.r#enum(*r#enum))
+        .for_each(|r#enum| {
+            let mut out = String::new();
+            render_documentation(&mut out, r#enum.ast_enum().documentation(), "");
+            writeln!(out, "enum {} {{", r#enum.name()).unwrap();
+            r#enum.values().for_each(|value| {
+                render_documentation(&mut out, value.documentation(), "  ");
+                writeln!(out, "  {}", value.name()).unwrap();
+            });
+            out.push_str("}\n");
+            definitions.push(out);
+        });
+    db.walk_composite_types()
+        .filter(|composite_type| kept.composite_type(*composite_type))
+        .for_each(|composite_type| {
+            let mut out = String::new();
+            render_documentation(
+                &mut out,
+                composite_type.ast_composite_type().documentation(),
+                "",
+            );
+            writeln!(out, "type {} {{", composite_type.name()).unwrap();
+            composite_type.fields().for_each(|field| {
+                if let Some(type_name) = renderer.field_type(field.r#type()) {
+                    render_documentation(&mut out, field.documentation(), "  ");
+                    writeln!(
+                        out,
+                        "  {}: {}",
+                        field.name(),
+                        with_arity(type_name, field.arity())
+                    )
+                    .unwrap();
+                }
+            });
+            out.push_str("}\n");
+            definitions.push(out);
+        });
+    db.walk_models()
+        .chain(db.walk_views())
+        .filter(|model| !model.is_ignored() && kept.model(*model))
+        .for_each(|model| {
+            let mut out = String::new();
+            renderer.render_model(&mut out, model);
+            definitions.push(out);
+        });
+    if !renderer.scalars.is_empty() {
+        let scalars: String = renderer
+            .scalars
+            .iter()
+            .map(|name| format!("scalar {}\n", name))
+            .collect();
+        definitions.insert(0, scalars);
+    }
+    definitions.join("\n")
+}
@@ -0,0 +1,345 @@
+//! Syntax highlighting of schema source, driven by the spans of the `schema_ast` nodes
+use std::fmt::Write;
+
+use colored::{ColoredString, Colorize};
+use psl_core::{
+    diagnostics::{Diagnostics, Span},
+    schema_ast::{
+        ast::{Argument, Attribute, Expression, Field, FieldType, Top, WithIdentifier, WithSpan},
+        parse_schema,
+    },
+};
+use serde::Serialize;
+
+use crate::serialize_span;
+
+const KEYWORDS: [&str; 6] = ["model", "view", "enum", "type", "datasource", "generator"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenKind {
+    /// `model`, `view`, `enum`, `type`, `datasource` and `generator`
+    Keyword,
+    /// Names of declarations, fields, enum values, properties and arguments
+    Identifier,
+    /// Field types, including `Unsupported("...")`
+    Type,
+    /// `@name` and `@@name`
+    Attribute,
+    String,
+    Number,
+    Comment,
+    /// `///` comments, which become the documentation of what follows them
+    DocComment,
+    /// Braces, brackets, parentheses, separators and the `?`/`[]` modifiers
+    Punctuation,
+}
+
+impl TokenKind {
+    /// Class the HTML markup gives to tokens of this kind
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            TokenKind::Keyword => "token-keyword",
+            TokenKind::Identifier => "token-identifier",
+            TokenKind::Type => "token-type",
+            TokenKind::Attribute => "token-attribute",
+            TokenKind::String => "token-string",
+            TokenKind::Number => "token-number",
+            TokenKind::Comment => "token-comment",
+            TokenKind::DocComment => "token-doc-comment",
+            TokenKind::Punctuation => "token-punctuation",
+        }
+    }
+    /// Terminal colours matching the ones the web app gives to `css_class`
+    pub fn paint(&self, text: &str) -> ColoredString {
+        match self {
+            TokenKind::Keyword | TokenKind::Punctuation => text.magenta().bold(),
+            TokenKind::Identifier => text.normal(),
+            TokenKind::Type => text.bright_blue().italic(),
+            TokenKind::Attribute => text.yellow(),
+            TokenKind::String => text.green(),
+            TokenKind::Number => text.red(),
+            TokenKind::Comment => text.bright_black().italic(),
+            TokenKind::DocComment => text.cyan().italic(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Token {
+    #[serde(serialize_with = "serialize_span")]
+    pub span: Span,
+    pub kind: TokenKind,
+}
+
+/// A schema split into classified tokens, in source order and without overlaps.
+/// Whitespace and anything the parser could not make sense of is left out.
+pub struct Highlighter<'a> {
+    schema: &'a str,
+    declarations: Vec<(String, Span)>,
+    tokens: Vec<Token>,
+}
+
+impl<'a> Highlighter<'a> {
+    pub fn new(schema: &'a str) -> Highlighter<'a> {
+        let ast = parse_schema(schema, &mut Diagnostics::default());
+        let mut tokens = vec![];
+        ast.iter_tops()
+            .for_each(|(_, top)| push_top(&mut tokens, top));
+        tokens.sort_by_key(|token| token.span.start);
+
+        // Everything between the AST nodes: keywords, comments and punctuation
+        let mut gaps = vec![];
+        let mut position = 0;
+        tokens.iter().for_each(|token| {
+            lex_gap(schema, position, token.span.start, &mut gaps);
+            position = position.max(token.span.end);
+        });
+        lex_gap(schema, position, schema.len(), &mut gaps);
+        tokens.append(&mut gaps);
+        tokens.sort_by_key(|token| token.span.start);
+        // `@` and `@@` come out separately from the attribute name
+        tokens.dedup_by(|next, previous| {
+            let merge = previous.kind == TokenKind::Attribute
+                && next.kind == TokenKind::Attribute
+                && previous.span.end == next.span.start;
+            if merge {
+                previous.span.end = next.span.end;
+            }
+            merge
+        });
+
+        Highlighter {
+            schema,
+            declarations: ast
+                .iter_tops()
+                .map(|(_, top)| (top.name().to_string(), top.span()))
+                .collect(),
+            tokens,
+        }
+    }
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+    /// Name and span of every top level block, in source order
+    pub fn declarations(&self) -> &[(String, Span)] {
+        &self.declarations
+    }
+    /// Span of the model, view, enum, type, datasource or generator called `name`
+    pub fn declaration_span(&self, name: &str) -> Option<Span> {
+        self.declarations
+            .iter()
+            .find(|(declaration, _)| declaration == name)
+            .map(|(_, span)| *span)
+    }
+    /// Source text within `span`, cut into plain text and classified tokens
+    pub fn segments(&self, span: Span) -> Vec<(&'a str, Option<TokenKind>)> {
+        let mut segments = vec![];
+        let mut position = span.start;
+        self.tokens
+            .iter()
+            .filter(|token| token.span.start >= span.start && token.span.end <= span.end)
+            .for_each(|token| {
+                if token.span.start > position {
+                    segments.push((&self.schema[position..token.span.start], None));
+                }
+                segments.push((
+                    &self.schema[token.span.start..token.span.end],
+                    Some(token.kind),
+                ));
+                position = token.span.end;
+            });
+        if span.end > position {
+            segments.push((&self.schema[position..span.end], None));
+        }
+        segments
+    }
+    /// The source within `span` with terminal colours, unless colouring is turned off
+    pub fn ansi_for(&self, span: Span) -> String {
+        let mut out = String::new();
+        self.segments(span)
+            .into_iter()
+            .for_each(|(text, kind)| match kind {
+                Some(kind) => write!(out, "{}", kind.paint(text)).unwrap(),
+                None => out.push_str(text),
+            });
+        out
+    }
+    /// The whole schema as highlighted HTML
+    pub fn html(&self) -> String {
+        self.html_for(Span::new(0, self.schema.len()))
+    }
+    /// The source within `span` as a `<pre>` block of classed `<span>` elements
+    pub fn html_for(&self, span: Span) -> String {
+        let mut out = String::from(r#"<pre class="schema-code">"#);
+        self.segments(span)
+            .into_iter()
+            .for_each(|(text, kind)| match kind {
+                Some(kind) => write!(
+                    out,
+                    r#"<span class="{}">{}</span>"#,
+                    kind.css_class(),
+                    escape(text)
+                )
+                .unwrap(),
+                None => out.push_str(&escape(text)),
+            });
+        out.push_str("</pre>");
+        out
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn token(kind: TokenKind, span: Span) -> Token {
+    Token { span, kind }
+}
+
+fn push_top(tokens: &mut Vec<Token>, top: &Top) {
+    tokens.push(token(TokenKind::Identifier, top.identifier().span));
+    match top {
+        Top::Model(model) => {
+            model
+                .iter_fields()
+                .for_each(|(_, field)| push_field(tokens, field));
+            push_attributes(tokens, &model.attributes);
+        }
+        Top::CompositeType(composite_type) => {
+            composite_type
+                .iter_fields()
+                .for_each(|(_, field)| push_field(tokens, field));
+        }
+        Top::Enum(r#enum) => {
+            r#enum.values.iter().for_each(|value| {
+                tokens.push(token(TokenKind::Identifier, value.name.span));
+                push_attributes(tokens, &value.attributes);
+            });
+            push_attributes(tokens, &r#enum.attributes);
+        }
+        Top::Source(source) => source.properties.iter().for_each(|property| {
+            tokens.push(token(TokenKind::Identifier, property.name.span));
+            if let Some(value) = &property.value {
+                push_expression(tokens, value);
+            }
+        }),
+        Top::Generator(generator) => generator.properties.iter().for_each(|property| {
+            tokens.push(token(TokenKind::Identifier, property.name.span));
+            if let Some(value) = &property.value {
+                push_expression(tokens, value);
+            }
+        }),
+    }
+}
+
+fn push_field(tokens: &mut Vec<Token>, field: &Field) {
+    tokens.push(token(TokenKind::Identifier, field.identifier().span));
+    let type_span = match &field.field_type {
+        FieldType::Supported(identifier) => identifier.span,
+        FieldType::Unsupported(_, span) => *span,
+    };
+    tokens.push(token(TokenKind::Type, type_span));
+    push_attributes(tokens, &field.attributes);
+}
+
+fn push_attributes(tokens: &mut Vec<Token>, attributes: &[Attribute]) {
+    attributes.iter().for_each(|attribute| {
+        // The `@` or `@@` in front of the name is picked up between the nodes
+        tokens.push(token(TokenKind::Attribute, attribute.name.span));
+        attribute
+            .arguments
+            .arguments
+            .iter()
+            .for_each(|argument| push_argument(tokens, argument));
+        attribute
+            .arguments
+            .empty_arguments
+            .iter()
+            .for_each(|argument| tokens.push(token(TokenKind::Identifier, argument.name.span)));
+    });
+}
+
+fn push_argument(tokens: &mut Vec<Token>, argument: &Argument) {
+    if let Some(name) = &argument.name {
+        tokens.push(token(TokenKind::Identifier, name.span));
+    }
+    push_expression(tokens, &argument.value);
+}
+
+fn push_expression(tokens: &mut Vec<Token>, expression: &Expression) {
+    match expression {
+        Expression::NumericValue(_, span) => tokens.push(token(TokenKind::Number, *span)),
+        Expression::StringValue(_, span) => tokens.push(token(TokenKind::String, *span)),
+        Expression::ConstantValue(_, span) => tokens.push(token(TokenKind::Identifier, *span)),
+        Expression::Function(name, arguments, span) => {
+            tokens.push(token(
+                TokenKind::Identifier,
+                Span::new(span.start, span.start + name.len()),
+            ));
+            arguments
+                .arguments
+                .iter()
+                .for_each(|argument| push_argument(tokens, argument));
+        }
+        Expression::Array(values, _) => values
+            .iter()
+            .for_each(|value| push_expression(tokens, value)),
+    }
+}
+
+/// Classifies what the AST does not cover between `start` and `end`
+fn lex_gap(schema: &str, start: usize, end: usize, tokens: &mut Vec<Token>) {
+    let mut position = start;
+    while position < end {
+        let rest = &schema[position..end];
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+        let length = if rest.starts_with("//") {
+            let length = rest.find('\n').unwrap_or(rest.len());
+            let kind = match rest.starts_with("///") {
+                true => TokenKind::DocComment,
+                false => TokenKind::Comment,
+            };
+            tokens.push(token(kind, Span::new(position, position + length)));
+            length
+        } else if c == '@' {
+            let length = rest.find(|c| c != '@').unwrap_or(rest.len());
+            tokens.push(token(
+                TokenKind::Attribute,
+                Span::new(position, position + length),
+            ));
+            length
+        } else if "{}()[],:=?.".contains(c) {
+            tokens.push(token(
+                TokenKind::Punctuation,
+                Span::new(position, position + 1),
+            ));
+            1
+        } else if c.is_alphanumeric() || c == '_' {
+            let length = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..length];
+            if KEYWORDS.contains(&word) {
+                tokens.push(token(
+                    TokenKind::Keyword,
+                    Span::new(position, position + length),
+                ));
+            } else if word == "Unsupported" {
+                tokens.push(token(
+                    TokenKind::Type,
+                    Span::new(position, position + length),
+                ));
+            }
+            length
+        } else {
+            c.len_utf8()
+        };
+        position += length;
+    }
+}
@@ -0,0 +1,1539 @@
+//! Builds a Prisma schema out of CREATE statements, for databases that are only at hand as a dump
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use psl::{
+    datamodel_connector::{constraint_names::ConstraintNames, Connector},
+    diagnostics::{Diagnostics, Span},
+    parser_database::ScalarType,
+};
+
+use crate::sql::Dialect;
+
+/// Prisma schema for the tables, enums, keys and indexes declared in `ddl`. Statements other
+/// than CREATE TABLE, CREATE INDEX, CREATE TYPE .. AS ENUM and ALTER TABLE .. ADD are skipped.
+pub fn import(ddl: &str, dialect: Dialect) -> Result<String, ImportError> {
+    let statements = Parser::new(ddl, dialect).parse();
+    let schema = Schema::new(statements, dialect).render();
+    psl::reformat(&schema, 2).ok_or_else(|| {
+        let mut diagnostics = Diagnostics::new();
+        psl::schema_ast::parse_schema(&schema, &mut diagnostics);
+        ImportError {
+            schema,
+            diagnostics,
+        }
+    })
+}
+
+/// A schema built out of the DDL that does not even parse, so cannot be formatted
+#[derive(Debug)]
+pub struct ImportError {
+    /// The schema as built, unformatted
+    pub schema: String,
+    /// Why `schema` does not parse, spans in `schema`
+    pub diagnostics: Diagnostics,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    /// `"name"`, `` `name` `` or `[name]`
+    Quoted(String),
+    String(String),
+    Number(String),
+    Punctuation(char),
+}
+
+/// Tokens with their start and end offset
+fn tokenize(ddl: &str, dialect: Dialect) -> Vec<(Token, usize, usize)> {
+    let mut tokens = vec![];
+    let chars: Vec<(usize, char)> = ddl.char_indices().collect();
+    let end_of = |index: usize| chars.get(index).map(|(i, _)| *i).unwrap_or(ddl.len());
+    let mut index = 0;
+    while index < chars.len() {
+        let (start, c) = chars[index];
+        let next = chars.get(index + 1).map(|(_, c)| *c);
+        if c.is_whitespace() {
+            index += 1;
+        } else if (c == '-' && next == Some('-')) || c == '#' {
+            while index < chars.len() && chars[index].1 != '\n' {
+                index += 1;
+            }
+        } else if c == '/' && next == Some('*') {
+            index += 2;
+            while index < chars.len()
+                && !(chars[index].1 == '*' && chars.get(index + 1).map(|(_, c)| *c) == Some('/'))
+            {
+                index += 1;
+            }
+            index += 2;
+        } else if c == '\'' || c == '"' || c == '`' || (c == '[' && dialect == Dialect::Sqlserver) {
+            let close = match c {
+                '[' => ']',
+                _ => c,
+            };
+            let mut value = String::new();
+            index += 1;
+            while index < chars.len() {
+                let current = chars[index].1;
+                if current == close {
+                    // A doubled quote stands for the quote itself
+                    if close != ']' && chars.get(index + 1).map(|(_, c)| *c) == Some(close) {
+                        value.push(close);
+                        index += 2;
+                        continue;
+                    }
+                    break;
+                }
+                if current == '\\' && c == '\'' {
+                    if let Some((_, escaped)) = chars.get(index + 1) {
+                        value.push(*escaped);
+                        index += 2;
+                        continue;
+                    }
+                }
+                value.push(current);
+                index += 1;
+            }
+            index += 1;
+            let token = match c {
+                '\'' => Token::String(value),
+                _ => Token::Quoted(value),
+            };
+            tokens.push((token, start, end_of(index)));
+        } else if c.is_ascii_digit() || (c == '.' && next.is_some_and(|c| c.is_ascii_digit())) {
+            let begin = index;
+            while index < chars.len()
+                && (chars[index].1.is_ascii_alphanumeric() || chars[index].1 == '.')
+            {
+                index += 1;
+            }
+            let value: String = chars[begin..index].iter().map(|(_, c)| c).collect();
+            tokens.push((Token::Number(value), start, end_of(index)));
+        } else if c.is_alphanumeric() || c == '_' || c == '$' {
+            let begin = index;
+            while index < chars.len()
+                && (chars[index].1.is_alphanumeric() || "_$".contains(chars[index].1))
+            {
+                index += 1;
+            }
+            let value: String = chars[begin..index].iter().map(|(_, c)| c).collect();
+            tokens.push((Token::Word(value), start, end_of(index)));
+        } else {
+            tokens.push((Token::Punctuation(c), start, end_of(index + 1)));
+            index += 1;
+        }
+    }
+    tokens
+}
+
+#[derive(Clone, Debug, Default)]
+struct Column {
+    name: String,
+    /// Lowercase words of the type, like `["double", "precision"]`
+    type_words: Vec<String>,
+    /// Type as written in the DDL
+    type_text: String,
+    args: Vec<String>,
+    array: bool,
+    not_null: bool,
+    autoincrement: bool,
+    default: Vec<Token>,
+    default_text: String,
+}
+
+#[derive(Clone, Debug, Default)]
+struct IndexColumn {
+    name: String,
+    descending: bool,
+    length: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Key {
+    name: Option<String>,
+    columns: Vec<IndexColumn>,
+    algorithm: Option<String>,
+}
+
+impl Key {
+    fn column_names(&self) -> Vec<&str> {
+        self.columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct ForeignKey {
+    name: Option<String>,
+    columns: Vec<String>,
+    table: String,
+    references: Vec<String>,
+    on_delete: Option<String>,
+    on_update: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+struct Table {
+    name: String,
+    columns: Vec<Column>,
+    primary_key: Option<Key>,
+    uniques: Vec<Key>,
+    indexes: Vec<Key>,
+    foreign_keys: Vec<ForeignKey>,
+}
+
+/// Named enum types, or the inline `ENUM(..)` column types of MySQL
+#[derive(Clone, Debug)]
+struct Enum {
+    name: String,
+    values: Vec<String>,
+}
+
+#[derive(Default)]
+struct Statements {
+    tables: Vec<Table>,
+    enums: Vec<Enum>,
+}
+
+impl Statements {
+    fn table(&mut self, name: &str) -> Option<&mut Table> {
+        self.tables.iter_mut().find(|table| table.name == name)
+    }
+}
+
+/// Words that end a column type and start its constraints
+const COLUMN_CONSTRAINTS: [&str; 17] = [
+    "not",
+    "null",
+    "default",
+    "primary",
+    "unique",
+    "references",
+    "check",
+    "constraint",
+    "collate",
+    "auto_increment",
+    "autoincrement",
+    "identity",
+    "generated",
+    "comment",
+    "on",
+    "as",
+    "charset",
+];
+
+struct Parser {
+    tokens: Vec<(Token, usize, usize)>,
+    source: String,
+    position: usize,
+}
+
+impl Parser {
+    fn new(ddl: &str, dialect: Dialect) -> Parser {
+        Parser {
+            tokens: tokenize(ddl, dialect),
+            source: ddl.to_string(),
+            position: 0,
+        }
+    }
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position).map(|(token, _, _)| token)
+    }
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens
+            .get(self.position + offset)
+            .map(|(token, _, _)| token)
+    }
+    fn next(&mut self) -> Option<Token> {
+        let token = self.peek().cloned();
+        self.position += 1;
+        token
+    }
+    fn is_word(&self, word: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(w)) if w.eq_ignore_ascii_case(word))
+    }
+    fn eat_word(&mut self, word: &str) -> bool {
+        let found = self.is_word(word);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+    fn eat_words(&mut self, words: &[&str]) -> bool {
+        let found = words.iter().enumerate().all(|(offset, word)| {
+            matches!(self.peek_at(offset), Some(Token::Word(w)) if w.eq_ignore_ascii_case(word))
+        });
+        if found {
+            self.position += words.len();
+        }
+        found
+    }
+    fn is_punctuation(&self, c: char) -> bool {
+        self.peek() == Some(&Token::Punctuation(c))
+    }
+    fn eat_punctuation(&mut self, c: char) -> bool {
+        let found = self.is_punctuation(c);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+    fn at_end(&self) -> bool {
+        self.position >= self.tokens.len()
+    }
+    /// At a `,` or `)` that closes the current item, or at the end of the statement
+    fn at_item_end(&self) -> bool {
+        self.at_end()
+            || self.is_punctuation(',')
+            || self.is_punctuation(')')
+            || self.is_punctuation(';')
+    }
+    /// At the first word after a column type, MySQL's `CHARACTER SET` included
+    fn at_column_constraint(&self) -> bool {
+        match self.peek() {
+            Some(Token::Word(word)) => {
+                COLUMN_CONSTRAINTS.contains(&word.to_lowercase().as_str())
+                    || (word.eq_ignore_ascii_case("character")
+                        && matches!(self.peek_at(1), Some(Token::Word(set)) if set.eq_ignore_ascii_case("set")))
+            }
+            _ => true,
+        }
+    }
+    fn identifier(&mut self) -> Option<String> {
+        match self.peek() {
+            Some(Token::Word(word)) | Some(Token::Quoted(word)) => {
+                let word = word.clone();
+                self.position += 1;
+                Some(word)
+            }
+            _ => None,
+        }
+    }
+    /// `schema.table` is read as `table`
+    fn qualified_name(&mut self) -> Option<String> {
+        let mut name = self.identifier()?;
+        while self.is_punctuation('.') {
+            self.position += 1;
+            name = self.identifier()?;
+        }
+        Some(name)
+    }
+    /// Skips a balanced `( .. )` group, when there is one
+    fn skip_group(&mut self) {
+        if !self.is_punctuation('(') {
+            return;
+        }
+        let mut depth = 0;
+        while let Some(token) = self.next() {
+            match token {
+                Token::Punctuation('(') => depth += 1,
+                Token::Punctuation(')') => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+    fn skip_item(&mut self) {
+        while !self.at_item_end() {
+            if self.is_punctuation('(') {
+                self.skip_group();
+            } else {
+                self.position += 1;
+            }
+        }
+    }
+    fn skip_statement(&mut self) {
+        while !self.at_end() && !self.eat_punctuation(';') {
+            if self.is_punctuation('(') {
+                self.skip_group();
+            } else {
+                self.position += 1;
+            }
+        }
+    }
+    /// `(a, b DESC, c(10))`
+    fn index_columns(&mut self) -> Vec<IndexColumn> {
+        let mut columns = vec![];
+        if !self.eat_punctuation('(') {
+            return columns;
+        }
+        while !self.at_end() && !self.eat_punctuation(')') {
+            let Some(name) = self.identifier() else {
+                self.skip_item();
+                self.eat_punctuation(',');
+                continue;
+            };
+            let mut column = IndexColumn {
+                name,
+                ..Default::default()
+            };
+            if self.eat_punctuation('(') {
+                if let Some(Token::Number(length)) = self.next() {
+                    column.length = Some(length);
+                }
+                self.eat_punctuation(')');
+            }
+            while !self.at_item_end() {
+                if self.eat_word("desc") {
+                    column.descending = true;
+                } else if self.is_punctuation('(') {
+                    self.skip_group();
+                } else {
+                    self.position += 1;
+                }
+            }
+            columns.push(column);
+            self.eat_punctuation(',');
+        }
+        columns
+    }
+    fn identifiers(&mut self) -> Vec<String> {
+        self.index_columns()
+            .into_iter()
+            .map(|column| column.name)
+            .collect()
+    }
+    fn referential_action(&mut self) -> Option<String> {
+        let action = if self.eat_word("cascade") {
+            "Cascade"
+        } else if self.eat_word("restrict") {
+            "Restrict"
+        } else if self.eat_words(&["no", "action"]) {
+            "NoAction"
+        } else if self.eat_words(&["set", "null"]) {
+            "SetNull"
+        } else if self.eat_words(&["set", "default"]) {
+            "SetDefault"
+        } else {
+            return None;
+        };
+        Some(action.to_string())
+    }
+    /// `REFERENCES table (columns) ON DELETE .. ON UPDATE ..`
+    fn references(&mut self, foreign_key: &mut ForeignKey) {
+        foreign_key.table = self.qualified_name().unwrap_or_default();
+        foreign_key.references = self.identifiers();
+        while !self.at_item_end() {
+            if self.eat_words(&["on", "delete"]) {
+                foreign_key.on_delete = self.referential_action();
+            } else if self.eat_words(&["on", "update"]) {
+                foreign_key.on_update = self.referential_action();
+            } else if self.is_punctuation('(') {
+                self.skip_group();
+            } else if COLUMN_CONSTRAINTS[..8]
+                .iter()
+                .any(|word| self.is_word(word))
+            {
+                return;
+            } else {
+                self.position += 1;
+            }
+        }
+    }
+    fn parse(mut self) -> Statements {
+        let mut statements = Statements::default();
+        while !self.at_end() {
+            if self.eat_word("create") {
+                self.eat_words(&["or", "replace"]);
+                ["temporary", "temp", "unlogged", "global", "local"]
+                    .iter()
+                    .for_each(|word| {
+                        self.eat_word(word);
+                    });
+                if self.eat_word("table") {
+                    self.create_table(&mut statements);
+                } else if self.eat_word("type") {
+                    self.create_enum(&mut statements);
+                } else {
+                    let unique = self.eat_word("unique");
+                    self.eat_word("clustered");
+                    self.eat_word("nonclustered");
+                    if self.eat_word("index") {
+                        self.create_index(&mut statements, unique);
+                    }
+                }
+            } else if self.eat_word("alter") && self.eat_word("table") {
+                self.alter_table(&mut statements);
+            }
+            self.skip_statement();
+        }
+        statements
+    }
+    fn create_enum(&mut self, statements: &mut Statements) {
+        let Some(name) = self.qualified_name() else {
+            return;
+        };
+        if !self.eat_words(&["as", "enum"]) || !self.eat_punctuation('(') {
+            return;
+        }
+        let mut values = vec![];
+        while let Some(token) = self.next() {
+            match token {
+                Token::String(value) => values.push(value),
+                Token::Punctuation(')') => break,
+                _ => (),
+            }
+        }
+        statements.enums.push(Enum { name, values });
+    }
+    fn create_table(&mut self, statements: &mut Statements) {
+        self.eat_words(&["if", "not", "exists"]);
+        let Some(name) = self.qualified_name() else {
+            return;
+        };
+        let mut table = Table {
+            name,
+            ..Default::default()
+        };
+        if !self.eat_punctuation('(') {
+            return;
+        }
+        while !self.at_end() && !self.eat_punctuation(')') {
+            self.table_item(&mut table, &mut statements.enums);
+            self.skip_item();
+            self.eat_punctuation(',');
+        }
+        statements.tables.push(table);
+    }
+    /// Table level constraints, which CREATE TABLE and ALTER TABLE .. ADD share
+    fn table_constraint(&mut self, table: &mut Table) -> bool {
+        let name = match self.eat_word("constraint") {
+            true => self.identifier(),
+            false => None,
+        };
+        if self.eat_words(&["primary", "key"]) {
+            self.eat_word("clustered");
+            self.eat_word("nonclustered");
+            let columns = self.index_columns();
+            // Key columns are NOT NULL whether or not they say so
+            table
+                .columns
+                .iter_mut()
+                .filter(|column| columns.iter().any(|key| key.name == column.name))
+                .for_each(|column| column.not_null = true);
+            table.primary_key = Some(Key {
+                name,
+                columns,
+                algorithm: None,
+            });
+        } else if self.eat_word("unique") {
+            let _ = self.eat_word("key") || self.eat_word("index");
+            self.eat_word("nonclustered");
+            self.eat_word("clustered");
+            let name = match self.is_punctuation('(') {
+                true => name,
+                false => self.identifier().or(name),
+            };
+            table.uniques.push(Key {
+                name,
+                columns: self.index_columns(),
+                algorithm: None,
+            });
+        } else if self.eat_words(&["foreign", "key"]) {
+            let mut foreign_key = ForeignKey {
+                name,
+                columns: self.identifiers(),
+                ..Default::default()
+            };
+            if self.eat_word("references") {
+                self.references(&mut foreign_key);
+            }
+            table.foreign_keys.push(foreign_key);
+        } else if self.is_word("key") || self.is_word("index") {
+            self.position += 1;
+            let name = match self.is_punctuation('(') {
+                true => None,
+                false => self.identifier(),
+            };
+            table.indexes.push(Key {
+                name,
+                columns: self.index_columns(),
+                algorithm: None,
+            });
+        } else if name.is_some()
+            || self.is_word("check")
+            || self.is_word("fulltext")
+            || self.is_word("spatial")
+            || self.is_word("exclude")
+        {
+            // CHECK constraints and index kinds the schema can not express
+        } else {
+            return false;
+        }
+        true
+    }
+    fn table_item(&mut self, table: &mut Table, enums: &mut Vec<Enum>) {
+        if self.table_constraint(table) {
+            return;
+        }
+        let Some(name) = self.identifier() else {
+            return;
+        };
+        let mut column = Column {
+            name,
+            ..Default::default()
+        };
+        let type_start = self.tokens.get(self.position).map(|(_, start, _)| *start);
+        let mut type_end = type_start.unwrap_or_default();
+        loop {
+            match self.peek().cloned() {
+                Some(Token::Word(word)) if !self.at_column_constraint() => {
+                    column.type_words.push(word.to_lowercase());
+                }
+                Some(Token::Quoted(word)) if column.type_words.is_empty() => {
+                    column.type_words.push(word)
+                }
+                // `schema.type` is read as `type`
+                Some(Token::Punctuation('.')) => column.type_words.clear(),
+                Some(Token::Punctuation('(')) => {
+                    self.position += 1;
+                    let mut depth = 1;
+                    while let Some(token) = self.next() {
+                        match token {
+                            Token::Punctuation('(') => depth += 1,
+                            Token::Punctuation(')') if depth == 1 => break,
+                            Token::Punctuation(')') => depth -= 1,
+                            Token::Number(value) | Token::Word(value) | Token::String(value) => {
+                                column.args.push(value)
+                            }
+                            _ => (),
+                        }
+                    }
+                    type_end = self.tokens[self.position - 1].2;
+                    continue;
+                }
+                Some(Token::Punctuation('[')) => {
+                    self.position += 1;
+                    while !self.at_end() && !self.eat_punctuation(']') {
+                        self.position += 1;
+                    }
+                    column.array = true;
+                    type_end = self.tokens[self.position - 1].2;
+                    continue;
+                }
+                _ => break,
+            }
+            type_end = self.tokens[self.position].2;
+            self.position += 1;
+        }
+        column.type_text = type_start
+            .map(|start| self.source[start..type_end].to_string())
+            .unwrap_or_default();
+        if column.type_words.first().map(String::as_str) == Some("enum") {
+            let name = format!("{}_{}", table.name, column.name);
+            enums.push(Enum {
+                name: name.clone(),
+                values: column.args.clone(),
+            });
+            column.type_words = vec![name];
+            column.args.clear();
+        }
+        if column
+            .type_words
+            .first()
+            .is_some_and(|word| word.ends_with("serial"))
+        {
+            column.autoincrement = true;
+        }
+        self.column_constraints(table, &mut column);
+        table.columns.push(column);
+    }
+    fn column_constraints(&mut self, table: &mut Table, column: &mut Column) {
+        while !self.at_item_end() {
+            if self.eat_words(&["not", "null"]) {
+                column.not_null = true;
+            } else if self.eat_words(&["primary", "key"]) {
+                column.not_null = true;
+                if self.eat_word("autoincrement") {
+                    column.autoincrement = true;
+                }
+                table.primary_key = Some(Key {
+                    name: table.primary_key.take().and_then(|key| key.name),
+                    columns: vec![IndexColumn {
+                        name: column.name.clone(),
+                        ..Default::default()
+                    }],
+                    algorithm: None,
+                });
+            } else if self.eat_word("unique") {
+                self.eat_word("key");
+                table.uniques.push(Key {
+                    name: None,
+                    columns: vec![IndexColumn {
+                        name: column.name.clone(),
+                        ..Default::default()
+                    }],
+                    algorithm: None,
+                });
+            } else if self.eat_word("references") {
+                let mut foreign_key = ForeignKey {
+                    columns: vec![column.name.clone()],
+                    ..Default::default()
+                };
+                self.references(&mut foreign_key);
+                table.foreign_keys.push(foreign_key);
+            } else if self.eat_word("default") {
+                let start = self.tokens.get(self.position).map(|(_, start, _)| *start);
+                let mut end = start.unwrap_or_default();
+                while !self.at_item_end()
+                    && !COLUMN_CONSTRAINTS[..12]
+                        .iter()
+                        .any(|word| self.is_word(word) && *word != "null")
+                    && !self.is_word("on")
+                {
+                    if self.is_punctuation('(') {
+                        let from = self.position;
+                        self.skip_group();
+                        column.default.extend(
+                            self.tokens[from..self.position]
+                                .iter()
+                                .map(|(t, _, _)| t.clone()),
+                        );
+                    } else {
+                        column.default.push(self.next().unwrap());
+                    }
+                    end = self.tokens[self.position - 1].2;
+                }
+                column.default_text = start
+                    .map(|start| self.source[start..end].to_string())
+                    .unwrap_or_default();
+            } else if self.eat_word("auto_increment")
+                || self.eat_word("autoincrement")
+                || self.eat_word("identity")
+            {
+                column.autoincrement = true;
+                self.skip_group();
+            } else if self.eat_word("generated") {
+                // GENERATED .. AS IDENTITY, or a computed column
+                while !self.at_item_end() && !self.is_word("identity") && !self.is_punctuation('(')
+                {
+                    self.position += 1;
+                }
+                column.autoincrement = self.eat_word("identity");
+                self.skip_group();
+            } else if self.eat_word("constraint") {
+                self.identifier();
+            } else if self.is_punctuation('(') {
+                self.skip_group();
+            } else {
+                self.position += 1;
+            }
+        }
+    }
+    fn create_index(&mut self, statements: &mut Statements, unique: bool) {
+        self.eat_word("concurrently");
+        self.eat_words(&["if", "not", "exists"]);
+        let name = match self.is_word("on") {
+            true => None,
+            false => self.identifier(),
+        };
+        if !self.eat_word("on") {
+            return;
+        }
+        self.eat_word("only");
+        let Some(table_name) = self.qualified_name() else {
+            return;
+        };
+        let algorithm = match self.eat_word("using") {
+            true => self.identifier(),
+            false => None,
+        };
+        let key = Key {
+            name,
+            columns: self.index_columns(),
+            algorithm,
+        };
+        if let Some(table) = statements.table(&table_name) {
+            match unique {
+                true => table.uniques.push(key),
+                false => table.indexes.push(key),
+            }
+        }
+    }
+    fn alter_table(&mut self, statements: &mut Statements) {
+        self.eat_words(&["if", "exists"]);
+        self.eat_word("only");
+        let Some(table_name) = self.qualified_name() else {
+            return;
+        };
+        while !self.at_end() && !self.is_punctuation(';') {
+            if self.eat_word("add") {
+                let Some(table) = statements.table(&table_name) else {
+                    return;
+                };
+                let mut altered = table.clone();
+                if self.table_constraint(&mut altered) {
+                    *statements.table(&table_name).unwrap() = altered;
+                }
+            }
+            self.skip_item();
+            self.eat_punctuation(',');
+        }
+    }
+}
+
+/// Prisma names for database names that are not valid identifiers, or not in Prisma casing
+fn pascal_case(name: &str) -> String {
+    let name: String = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    valid_identifier(name, "Model")
+}
+
+fn camel_case(name: &str) -> String {
+    if is_identifier(name) && !name.contains('_') {
+        return name.to_string();
+    }
+    valid_identifier(lower_first(&pascal_case(name)), "field")
+}
+
+fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn valid_identifier(name: String, prefix: &str) -> String {
+    match name.chars().next() {
+        Some(c) if c.is_ascii_alphabetic() => name,
+        _ => format!("{}{}", prefix, name),
+    }
+}
+
+fn enum_value_name(value: &str) -> String {
+    match is_identifier(value) {
+        true => value.to_string(),
+        false => {
+            let name: String = value
+                .chars()
+                .map(|c| match c.is_ascii_alphanumeric() {
+                    true => c,
+                    false => '_',
+                })
+                .collect();
+            valid_identifier(name, "VALUE_")
+        }
+    }
+}
+
+fn unique_name(name: String, taken: &HashSet<String>) -> String {
+    if !taken.contains(&name) {
+        return name;
+    }
+    (1..)
+        .map(|suffix| format!("{}_{}", name, suffix))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap()
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum FieldType {
+    Scalar(ScalarType),
+    Enum(usize),
+    Unsupported(String),
+}
+
+struct Field {
+    name: String,
+    column: Column,
+    field_type: FieldType,
+    native_type: Option<String>,
+}
+
+struct Model {
+    name: String,
+    table: Table,
+    fields: Vec<Field>,
+    relation_fields: Vec<String>,
+}
+
+impl Model {
+    fn field_name(&self, column: &str) -> String {
+        self.fields
+            .iter()
+            .find(|field| field.column.name == column)
+            .map(|field| field.name.clone())
+            .unwrap_or_else(|| camel_case(column))
+    }
+    fn field_names(&self, columns: &[&str]) -> String {
+        columns
+            .iter()
+            .map(|column| self.field_name(column))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+    fn taken_names(&self) -> HashSet<String> {
+        self.fields
+            .iter()
+            .map(|field| field.name.clone())
+            .chain(self.relation_fields.iter().map(|line| {
+                line.split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string()
+            }))
+            .collect()
+    }
+    /// The table has a primary key or a unique constraint over required columns
+    fn has_unique_criteria(&self) -> bool {
+        let required = |key: &Key| {
+            key.columns.iter().all(|column| {
+                self.table
+                    .columns
+                    .iter()
+                    .any(|c| c.name == column.name && c.not_null)
+            })
+        };
+        self.table.primary_key.is_some() || self.table.uniques.iter().any(required)
+    }
+    fn is_unique(&self, columns: &[&str]) -> bool {
+        let same = |key: &Key| {
+            let mut key = key.column_names();
+            let mut columns = columns.to_vec();
+            key.sort();
+            columns.sort();
+            key == columns
+        };
+        self.table.primary_key.iter().any(same) || self.table.uniques.iter().any(same)
+    }
+}
+
+struct Schema {
+    dialect: Dialect,
+    models: Vec<Model>,
+    enums: Vec<(String, Enum)>,
+}
+
+impl Schema {
+    fn new(statements: Statements, dialect: Dialect) -> Schema {
+        let mut taken = HashSet::new();
+        let enums: Vec<(String, Enum)> = statements
+            .enums
+            .into_iter()
+            .map(|r#enum| {
+                let name = unique_name(pascal_case(&r#enum.name), &taken);
+                taken.insert(name.clone());
+                (name, r#enum)
+            })
+            .collect();
+        let mut schema = Schema {
+            dialect,
+            models: vec![],
+            enums,
+        };
+        statements.tables.into_iter().for_each(|table| {
+            let name = unique_name(pascal_case(&table.name), &taken);
+            taken.insert(name.clone());
+            let mut field_names = HashSet::new();
+            let fields = table
+                .columns
+                .iter()
+                .map(|column| {
+                    let name = unique_name(camel_case(&column.name), &field_names);
+                    field_names.insert(name.clone());
+                    let (field_type, native_type) = schema.field_type(column);
+                    Field {
+                        name,
+                        column: column.clone(),
+                        field_type,
+                        native_type,
+                    }
+                })
+                .collect();
+            schema.models.push(Model {
+                name,
+                table,
+                fields,
+                relation_fields: vec![],
+            });
+        });
+        schema.relations();
+        schema
+    }
+    fn connector(&self) -> &'static dyn Connector {
+        self.dialect.connector()
+    }
+    fn provider(&self) -> &'static str {
+        match self.dialect {
+            Dialect::Postgres => "postgresql",
+            Dialect::Cockroachdb => "cockroachdb",
+            Dialect::Mysql => "mysql",
+            Dialect::Sqlserver => "sqlserver",
+            Dialect::Sqlite => "sqlite",
+        }
+    }
+    /// Native type constructor name of a column type, after resolving the dialect's aliases
+    fn native_type_name(&self, column: &Column) -> Option<(String, Vec<String>)> {
+        let words = column.type_words.join(" ");
+        let mut args = column.args.clone();
+        let name = match (self.dialect, words.as_str()) {
+            (Dialect::Postgres, "int" | "int4" | "integer" | "serial" | "serial4") => "Integer",
+            (Dialect::Postgres, "int2" | "smallint" | "smallserial" | "serial2") => "SmallInt",
+            (Dialect::Postgres, "int8" | "bigint" | "bigserial" | "serial8") => "BigInt",
+            (Dialect::Postgres, "float4") => "Real",
+            (Dialect::Postgres, "float8" | "double precision" | "float") => "DoublePrecision",
+            (Dialect::Postgres, "bool") => "Boolean",
+            (Dialect::Postgres, "bpchar" | "character") => "Char",
+            (Dialect::Postgres, "timestamp without time zone") => "Timestamp",
+            (Dialect::Postgres, "timestamp with time zone") => "Timestamptz",
+            (Dialect::Postgres, "time without time zone") => "Time",
+            (Dialect::Postgres, "time with time zone") => "Timetz",
+            (Dialect::Postgres, "bit varying") => "VarBit",
+            (Dialect::Cockroachdb, "int" | "integer" | "bigint" | "serial" | "serial8") => "Int8",
+            (Dialect::Cockroachdb, "smallint" | "serial2") => "Int2",
+            (Dialect::Cockroachdb, "serial4") => "Int4",
+            (Dialect::Cockroachdb, "float" | "double precision") => "Float8",
+            (Dialect::Cockroachdb, "real") => "Float4",
+            (Dialect::Cockroachdb, "boolean") => "Bool",
+            (Dialect::Cockroachdb, "text" | "varchar" | "character varying") => "String",
+            (Dialect::Cockroachdb, "bytea") => "Bytes",
+            (Dialect::Cockroachdb, "json") => "JsonB",
+            (Dialect::Cockroachdb, "timestamp without time zone") => "Timestamp",
+            (Dialect::Cockroachdb, "timestamp with time zone") => "Timestamptz",
+            (Dialect::Postgres | Dialect::Cockroachdb, "numeric") => "Decimal",
+            (Dialect::Postgres, "character varying") => "VarChar",
+            (Dialect::Mysql, "integer") => "Int",
+            (Dialect::Mysql, "bool" | "boolean") => "TinyInt",
+            (Dialect::Mysql, "double precision" | "real") => "Double",
+            (Dialect::Mysql, "numeric" | "dec" | "fixed") => "Decimal",
+            (Dialect::Mysql, "character varying") => "VarChar",
+            (Dialect::Mysql, "character") => "Char",
+            (Dialect::Mysql, words) if words.ends_with(" unsigned") => {
+                let signed = pascal_case(words.trim_end_matches(" unsigned"));
+                let signed = match signed.as_str() {
+                    "Integer" => "Int".to_string(),
+                    _ => signed,
+                };
+                return self.constructor(&format!("Unsigned{}", signed), args);
+            }
+            (Dialect::Sqlserver, "integer") => "Int",
+            (Dialect::Sqlserver, "double precision") => {
+                args = vec!["53".to_string()];
+                "Float"
+            }
+            (_, words) => return self.constructor(words, args),
+        };
+        self.constructor(name, args)
+    }
+    /// Finds the connector's native type called `name`, ignoring case
+    fn constructor(&self, name: &str, args: Vec<String>) -> Option<(String, Vec<String>)> {
+        let compact = name.replace(' ', "");
+        let constructor = self
+            .connector()
+            .available_native_type_constructors()
+            .iter()
+            .find(|constructor| constructor.name.eq_ignore_ascii_case(&compact))?;
+        // `int(11)` and the like only carry a display width
+        let args = match constructor.number_of_args + constructor.number_of_optional_args {
+            0 => vec![],
+            _ => args,
+        };
+        Some((constructor.name.to_string(), args))
+    }
+    /// SQLite only has type affinities, which are picked by the words in the type name
+    fn sqlite_type(&self, column: &Column) -> FieldType {
+        let words = column.type_words.join(" ").to_uppercase();
+        let scalar_type = match words.as_str() {
+            "INTEGER" | "INT" | "SMALLINT" | "TINYINT" | "MEDIUMINT" => ScalarType::Int,
+            "BIGINT" | "INT8" | "UNSIGNED BIG INT" => ScalarType::BigInt,
+            "BOOLEAN" | "BOOL" => ScalarType::Boolean,
+            "DATETIME" | "DATE" | "TIMESTAMP" => ScalarType::DateTime,
+            "DECIMAL" | "NUMERIC" => ScalarType::Decimal,
+            "JSONB" => ScalarType::Json,
+            w if w.contains("INT") => ScalarType::Int,
+            w if w.contains("CHAR") || w.contains("CLOB") || w.contains("TEXT") => {
+                ScalarType::String
+            }
+            w if w.contains("BLOB") => ScalarType::Bytes,
+            w if w.contains("REAL") || w.contains("FLOA") || w.contains("DOUB") => {
+                ScalarType::Float
+            }
+            _ => return FieldType::Unsupported(column.type_text.clone()),
+        };
+        FieldType::Scalar(scalar_type)
+    }
+    fn field_type(&self, column: &Column) -> (FieldType, Option<String>) {
+        let type_name = column.type_words.join(" ");
+        if let Some(index) = self.enums.iter().position(|(_, r#enum)| {
+            r#enum.name == type_name || r#enum.name.eq_ignore_ascii_case(&type_name)
+        }) {
+            return (FieldType::Enum(index), None);
+        }
+        if self.dialect == Dialect::Sqlite {
+            return (self.sqlite_type(column), None);
+        }
+        let connector = self.connector();
+        let native_type = self.native_type_name(column).and_then(|(name, args)| {
+            connector
+                .parse_native_type(&name, &args, Span::empty(), &mut Diagnostics::default())
+                .map(|native_type| (name, native_type))
+        });
+        match native_type {
+            Some((name, native_type)) => {
+                let mut scalar_type = connector.scalar_type_for_native_type(&native_type);
+                // MySQL has no boolean type of its own
+                if self.dialect == Dialect::Mysql
+                    && name == "TinyInt"
+                    && (column.args == ["1"] || column.type_words[0].starts_with("bool"))
+                {
+                    scalar_type = ScalarType::Boolean;
+                }
+                let native_type = match connector
+                    .native_type_is_default_for_scalar_type(&native_type, &scalar_type)
+                {
+                    true => None,
+                    false => {
+                        let (name, args) = connector.native_type_to_parts(&native_type);
+                        Some(match args.is_empty() {
+                            true => format!("@db.{}", name),
+                            false => format!("@db.{}({})", name, args.join(", ")),
+                        })
+                    }
+                };
+                (FieldType::Scalar(scalar_type), native_type)
+            }
+            None => (FieldType::Unsupported(column.type_text.clone()), None),
+        }
+    }
+    /// Adds both sides of every foreign key whose table is in the DDL
+    fn relations(&mut self) {
+        let foreign_keys: Vec<(usize, ForeignKey)> = self
+            .models
+            .iter()
+            .enumerate()
+            .flat_map(|(index, model)| {
+                model
+                    .table
+                    .foreign_keys
+                    .iter()
+                    .map(move |foreign_key| (index, foreign_key.clone()))
+            })
+            .collect();
+        let target = |models: &[Model], table: &str| {
+            models
+                .iter()
+                .position(|model| model.table.name == table)
+                .or_else(|| {
+                    models
+                        .iter()
+                        .position(|model| model.table.name.eq_ignore_ascii_case(table))
+                })
+        };
+        let pairs: Vec<(usize, usize)> = foreign_keys
+            .iter()
+            .filter_map(|(from, foreign_key)| {
+                target(&self.models, &foreign_key.table).map(|to| (*from, to))
+            })
+            .collect();
+        foreign_keys.into_iter().for_each(|(from, foreign_key)| {
+            let Some(to) = target(&self.models, &foreign_key.table) else {
+                return;
+            };
+            let mut foreign_key = foreign_key;
+            if foreign_key.references.is_empty() {
+                foreign_key.references = self.models[to]
+                    .table
+                    .primary_key
+                    .as_ref()
+                    .map(|key| key.column_names().iter().map(|c| c.to_string()).collect())
+                    .unwrap_or_default();
+            }
+            let columns: Vec<&str> = foreign_key.columns.iter().map(String::as_str).collect();
+            let references: Vec<&str> = foreign_key.references.iter().map(String::as_str).collect();
+            let ambiguous = from == to
+                || pairs
+                    .iter()
+                    .filter(|pair| **pair == (from, to) || **pair == (to, from))
+                    .count()
+                    > 1;
+            let default_name = ConstraintNames::foreign_key_constraint_name(
+                &self.models[from].table.name,
+                &columns,
+                self.connector(),
+            );
+            let relation_name = match ambiguous {
+                true => Some(
+                    foreign_key
+                        .name
+                        .clone()
+                        .unwrap_or_else(|| default_name.clone()),
+                ),
+                false => None,
+            };
+
+            let model = &self.models[from];
+            let forward_name = match columns.as_slice() {
+                [column] => {
+                    let field = model.field_name(column);
+                    match field
+                        .strip_suffix("Id")
+                        .or_else(|| field.strip_suffix("ID"))
+                    {
+                        Some(stripped) if !stripped.is_empty() => stripped.to_string(),
+                        _ => lower_first(&self.models[to].name),
+                    }
+                }
+                _ => lower_first(&self.models[to].name),
+            };
+            let forward_name = unique_name(forward_name, &model.taken_names());
+            let optional = columns.iter().any(|column| {
+                model
+                    .table
+                    .columns
+                    .iter()
+                    .any(|c| c.name == *column && !c.not_null)
+            });
+            let one_to_one = model.is_unique(&columns);
+            let mut arguments = vec![];
+            if let Some(name) = &relation_name {
+                arguments.push(quote(name));
+            }
+            arguments.push(format!("fields: [{}]", model.field_names(&columns)));
+            arguments.push(format!(
+                "references: [{}]",
+                self.models[to].field_names(&references)
+            ));
+            if let Some(action) = &foreign_key.on_delete {
+                arguments.push(format!("onDelete: {}", action));
+            }
+            if let Some(action) = &foreign_key.on_update {
+                arguments.push(format!("onUpdate: {}", action));
+            }
+            if let Some(name) = foreign_key
+                .name
+                .as_ref()
+                .filter(|name| **name != default_name)
+            {
+                arguments.push(format!("map: {}", quote(name)));
+            }
+            // Relation fields of a kept model pointing at an `@@ignore`d one have to be ignored
+            // too, those of the ignored model go with it
+            let ignore = |owner: usize, target: usize| match !self.models[owner]
+                .has_unique_criteria()
+                || self.models[target].has_unique_criteria()
+            {
+                true => "",
+                false => " @ignore",
+            };
+            let (forward_ignore, back_ignore) = (ignore(from, to), ignore(to, from));
+            let forward = format!(
+                "{} {}{} @relation({}){}",
+                forward_name,
+                self.models[to].name,
+                if optional { "?" } else { "" },
+                arguments.join(", "),
+                forward_ignore
+            );
+            self.models[from].relation_fields.push(forward);
+
+            let back_name = match ambiguous {
+                true => format!(
+                    "{}{}",
+                    lower_first(&self.models[from].name),
+                    pascal_case(&forward_name)
+                ),
+                false => lower_first(&self.models[from].name),
+            };
+            let back_name = unique_name(back_name, &self.models[to].taken_names());
+            let back = format!(
+                "{} {}{}{}{}",
+                back_name,
+                self.models[from].name,
+                if one_to_one { "?" } else { "[]" },
+                relation_name
+                    .map(|name| format!(" @relation({})", quote(&name)))
+                    .unwrap_or_default(),
+                back_ignore
+            );
+            self.models[to].relation_fields.push(back);
+        });
+    }
+    fn default(&self, field: &Field) -> Option<String> {
+        let column = &field.column;
+        if column.autoincrement {
+            return Some("autoincrement()".to_string());
+        }
+        // Strip the parentheses SQL Server wraps defaults in, and casts like `'a'::text`
+        let mut tokens: &[Token] = &column.default;
+        while tokens.first() == Some(&Token::Punctuation('('))
+            && tokens.last() == Some(&Token::Punctuation(')'))
+        {
+            tokens = &tokens[1..tokens.len() - 1];
+        }
+        if let Some(cast) = tokens.windows(2).position(|pair| {
+            pair[0] == Token::Punctuation(':') && pair[1] == Token::Punctuation(':')
+        }) {
+            tokens = &tokens[..cast];
+        }
+        let generated = || {
+            Some(format!(
+                "dbgenerated({})",
+                quote(column.default_text.trim())
+            ))
+        };
+        let word = |token: &Token| match token {
+            Token::Word(word) => Some(word.to_lowercase()),
+            _ => None,
+        };
+        let number = match tokens {
+            [Token::Number(value)] | [Token::String(value)] => Some(value.clone()),
+            [Token::Punctuation('-'), Token::Number(value)] => Some(format!("-{}", value)),
+            _ => None,
+        }
+        .filter(|value| value.parse::<f64>().is_ok());
+        match (&field.field_type, tokens) {
+            (_, []) => None,
+            (_, [token]) if word(token).as_deref() == Some("null") => None,
+            (FieldType::Enum(index), [Token::String(value)]) => self.enums[*index]
+                .1
+                .values
+                .iter()
+                .find(|candidate| *candidate == value)
+                .map(|value| enum_value_name(value)),
+            (FieldType::Scalar(ScalarType::String | ScalarType::Json), [Token::String(value)]) => {
+                Some(quote(value))
+            }
+            (FieldType::Scalar(ScalarType::Boolean), [token]) => {
+                match (word(token).as_deref(), token) {
+                    (Some("true"), _) => Some("true".to_string()),
+                    (Some("false"), _) => Some("false".to_string()),
+                    (_, Token::Number(value) | Token::String(value)) => match value.as_str() {
+                        "1" | "b'1'" | "true" => Some("true".to_string()),
+                        "0" | "b'0'" | "false" => Some("false".to_string()),
+                        _ => generated(),
+                    },
+                    _ => generated(),
+                }
+            }
+            (
+                FieldType::Scalar(
+                    ScalarType::Int | ScalarType::BigInt | ScalarType::Float | ScalarType::Decimal,
+                ),
+                _,
+            ) if number.is_some() => number,
+            (FieldType::Scalar(ScalarType::Int | ScalarType::BigInt), [name, ..])
+                if word(name).as_deref() == Some("nextval") =>
+            {
+                Some("autoincrement()".to_string())
+            }
+            (FieldType::Scalar(ScalarType::DateTime), [name, ..])
+                if matches!(
+                    word(name).as_deref(),
+                    Some(
+                        "now" | "current_timestamp" | "localtimestamp" | "getdate" | "sysdatetime"
+                    )
+                ) =>
+            {
+                Some("now()".to_string())
+            }
+            _ => generated(),
+        }
+    }
+    fn render_model(&self, model: &Model, out: &mut String) {
+        let connector = self.connector();
+        let table = &model.table;
+        let ignored = !model.has_unique_criteria();
+        if ignored {
+            out.push_str("/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by Prisma Client.\n");
+        }
+        writeln!(out, "model {} {{", model.name).unwrap();
+        let single_column = |key: &Key| match key.columns.as_slice() {
+            [column] if !column.descending && column.length.is_none() => Some(column.name.clone()),
+            _ => None,
+        };
+        let primary_key_name = |key: &Key| {
+            key.name
+                .as_ref()
+                .filter(|_| connector.supports_named_primary_keys())
+                .filter(|name| **name != ConstraintNames::primary_key_name(&table.name, connector))
+                .map(|name| format!("map: {}", quote(name)))
+        };
+        let index_name = |key: &Key, unique: bool| {
+            let columns = key.column_names();
+            let default_name = match unique {
+                true => ConstraintNames::unique_index_name(&table.name, &columns, connector),
+                false => ConstraintNames::non_unique_index_name(&table.name, &columns, connector),
+            };
+            key.name
+                .as_ref()
+                .filter(|name| **name != default_name)
+                .map(|name| format!("map: {}", quote(name)))
+        };
+        let single_primary_key = table.primary_key.as_ref().and_then(single_column);
+        let single_uniques: Vec<(String, Option<String>)> = table
+            .uniques
+            .iter()
+            .filter_map(|key| single_column(key).map(|column| (column, index_name(key, true))))
+            .collect();
+        model.fields.iter().for_each(|field| {
+            let column = &field.column;
+            let type_name = match &field.field_type {
+                FieldType::Scalar(scalar_type) => scalar_type.as_str().to_string(),
+                FieldType::Enum(index) => self.enums[*index].0.clone(),
+                FieldType::Unsupported(name) => format!("Unsupported({})", quote(name)),
+            };
+            let modifier = match (column.array, column.not_null) {
+                (true, _) => "[]",
+                (false, true) => "",
+                (false, false) => "?",
+            };
+            let mut attributes = vec![];
+            if single_primary_key.as_deref() == Some(column.name.as_str()) {
+                match table.primary_key.as_ref().and_then(primary_key_name) {
+                    Some(name) => attributes.push(format!("@id({})", name)),
+                    None => attributes.push("@id".to_string()),
+                }
+            }
+            if let Some(value) = self.default(field) {
+                attributes.push(format!("@default({})", value));
+            }
+            if let Some((_, name)) = single_uniques
+                .iter()
+                .find(|(unique, _)| *unique == column.name)
+                .filter(|_| single_primary_key.as_deref() != Some(column.name.as_str()))
+            {
+                match name {
+                    Some(name) => attributes.push(format!("@unique({})", name)),
+                    None => attributes.push("@unique".to_string()),
+                }
+            }
+            if field.name != column.name {
+                attributes.push(format!("@map({})", quote(&column.name)));
+            }
+            if let Some(native_type) = &field.native_type {
+                attributes.push(native_type.clone());
+            }
+            writeln!(
+                out,
+                "  {} {}{} {}",
+                field.name,
+                type_name,
+                modifier,
+                attributes.join(" ")
+            )
+            .unwrap();
+        });
+        model
+            .relation_fields
+            .iter()
+            .for_each(|line| writeln!(out, "  {}", line).unwrap());
+        out.push('\n');
+
+        let block = |key: &Key| {
+            key.columns
+                .iter()
+                .map(|column| {
+                    let mut arguments = vec![];
+                    if let Some(length) = &column.length {
+                        arguments.push(format!("length: {}", length));
+                    }
+                    if column.descending {
+                        arguments.push("sort: Desc".to_string());
+                    }
+                    match arguments.is_empty() {
+                        true => model.field_name(&column.name),
+                        false => format!(
+                            "{}({})",
+                            model.field_name(&column.name),
+                            arguments.join(", ")
+                        ),
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        if let Some(key) = table
+            .primary_key
+            .as_ref()
+            .filter(|_| single_primary_key.is_none())
+        {
+            let mut arguments = vec![format!("[{}]", block(key))];
+            arguments.extend(primary_key_name(key));
+            writeln!(out, "  @@id({})", arguments.join(", ")).unwrap();
+        }
+        table
+            .uniques
+            .iter()
+            .filter(|key| single_column(key).is_none())
+            .for_each(|key| {
+                let mut arguments = vec![format!("[{}]", block(key))];
+                arguments.extend(index_name(key, true));
+                writeln!(out, "  @@unique({})", arguments.join(", ")).unwrap();
+            });
+        table.indexes.iter().for_each(|key| {
+            let mut arguments = vec![format!("[{}]", block(key))];
+            arguments.extend(index_name(key, false));
+            // Other access methods need operator classes the DDL rarely spells out
+            if key
+                .algorithm
+                .as_deref()
+                .is_some_and(|algorithm| algorithm.eq_ignore_ascii_case("hash"))
+                && self.dialect == Dialect::Postgres
+            {
+                arguments.push("type: Hash".to_string());
+            }
+            writeln!(out, "  @@index({})", arguments.join(", ")).unwrap();
+        });
+        if model.name != table.name {
+            writeln!(out, "  @@map({})", quote(&table.name)).unwrap();
+        }
+        if ignored {
+            out.push_str("  @@ignore\n");
+        }
+        out.push_str("}\n\n");
+    }
+    fn render(&self) -> String {
+        let mut out = String::new();
+        writeln!(
+            out,
+            "datasource db {{\n  provider = {}\n  url = env(\"DATABASE_URL\")\n}}\n",
+            quote(self.provider())
+        )
+        .unwrap();
+        self.models
+            .iter()
+            .for_each(|model| self.render_model(model, &mut out));
+        self.enums.iter().for_each(|(name, r#enum)| {
+            writeln!(out, "enum {} {{", name).unwrap();
+            r#enum.values.iter().for_each(|value| {
+                let value_name = enum_value_name(value);
+                match value_name == *value {
+                    true => writeln!(out, "  {}", value_name).unwrap(),
+                    false => writeln!(out, "  {} @map({})", value_name, quote(value)).unwrap(),
+                }
+            });
+            if *name != r#enum.name {
+                writeln!(out, "\n  @@map({})", quote(&r#enum.name)).unwrap();
+            }
+            out.push_str("}\n\n");
+        });
+        out
+    }
+}
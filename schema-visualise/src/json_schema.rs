@@ -0,0 +1,168 @@
+//! JSON Schema (draft 2020-12) for the models, views and composite types of a schema
+use psl::{
+    parser_database::{
+        walkers::{ModelWalker, RefinedFieldWalker},
+        ParserDatabase, ScalarFieldType, ScalarType,
+    },
+    schema_ast::ast::{FieldArity, WithDocumentation},
+};
+use serde_json::{json, Map, Value};
+
+use crate::selection::Kept;
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+fn scalar(scalar_type: ScalarType) -> Value {
+    match scalar_type {
+        ScalarType::Int | ScalarType::BigInt => json!({ "type": "integer" }),
+        ScalarType::Float => json!({ "type": "number" }),
+        // Decimals keep their precision as strings
+        ScalarType::Decimal | ScalarType::String => json!({ "type": "string" }),
+        ScalarType::Boolean => json!({ "type": "boolean" }),
+        ScalarType::DateTime => json!({ "type": "string", "format": "date-time" }),
+        ScalarType::Json => json!({}),
+        ScalarType::Bytes => json!({ "type": "string", "contentEncoding": "base64" }),
+    }
+}
+
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{}", name) })
+}
+
+fn field_schema(db: &ParserDatabase, r#type: ScalarFieldType) -> Option<Value> {
+    match r#type {
+        ScalarFieldType::BuiltInScalar(scalar_type) => Some(scalar(scalar_type)),
+        ScalarFieldType::Enum(enum_id) => {
+            let values: Vec<&str> = db
+                .walk(enum_id)
+                .values()
+                .map(|value| value.name())
+                .collect();
+            Some(json!({ "type": "string", "enum": values }))
+        }
+        ScalarFieldType::CompositeType(composite_type_id) => {
+            Some(reference(db.walk(composite_type_id).name()))
+        }
+        ScalarFieldType::Unsupported(_) => None,
+    }
+}
+
+/// Lets `null` through as well, in `type` and `enum` when there is one
+fn nullable(mut schema: Value) -> Value {
+    match schema.get("type").cloned() {
+        Some(Value::String(r#type)) => {
+            schema["type"] = json!([r#type, "null"]);
+            if let Some(Value::Array(values)) = schema.get_mut("enum") {
+                values.push(Value::Null);
+            }
+            schema
+        }
+        // `{}` accepts `null` already
+        _ if schema.as_object().is_some_and(|object| object.is_empty()) => schema,
+        _ => json!({ "anyOf": [schema, { "type": "null" }] }),
+    }
+}
+
+fn with_arity(schema: Value, arity: FieldArity) -> Value {
+    match arity {
+        FieldArity::Required => schema,
+        FieldArity::Optional => nullable(schema),
+        FieldArity::List => json!({ "type": "array", "items": schema }),
+    }
+}
+
+fn with_documentation(mut schema: Value, documentation: Option<&str>) -> Value {
+    if let Some(documentation) = documentation {
+        schema["description"] = json!(documentation);
+    }
+    schema
+}
+
+fn object(
+    documentation: Option<&str>,
+    properties: Map<String, Value>,
+    required: Vec<&str>,
+) -> Value {
+    let mut schema = with_documentation(json!({ "type": "object" }), documentation);
+    schema["properties"] = Value::Object(properties);
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+    schema
+}
+
+fn model(kept: &Kept, model: ModelWalker<'_>) -> Value {
+    let mut properties = Map::new();
+    let mut required = vec![];
+    model.fields().for_each(|field| {
+        let schema = match field.refine() {
+            RefinedFieldWalker::Scalar(scalar_field) if !scalar_field.is_ignored() => {
+                field_schema(model.db, scalar_field.scalar_field_type()).map(|schema| {
+                    let arity = scalar_field.ast_field().arity;
+                    if arity.is_required() && scalar_field.default_value().is_none() {
+                        required.push(scalar_field.name());
+                    }
+                    with_arity(schema, arity)
+                })
+            }
+            // Relations are only there when they were loaded along with the record
+            RefinedFieldWalker::Relation(relation_field)
+                if !relation_field.is_ignored()
+                    && !relation_field.related_model().is_ignored()
+                    && kept.relation_field(relation_field) =>
+            {
+                Some(with_arity(
+                    reference(relation_field.related_model().name()),
+                    relation_field.ast_field().arity,
+                ))
+            }
+            _ => None,
+        };
+        if let Some(schema) = schema {
+            properties.insert(
+                field.name().to_string(),
+                with_documentation(schema, field.ast_field().documentation()),
+            );
+        }
+    });
+    object(model.ast_model().documentation(), properties, required)
+}
+
+pub(crate) fn render(db: &ParserDatabase, kept: &Kept) -> Value {
+    let mut definitions = Map::new();
+    db.walk_composite_types()
+        .filter(|composite_type| kept.composite_type(*composite_type))
+        .for_each(|composite_type| {
+            let mut properties = Map::new();
+            let mut required = vec![];
+            composite_type.fields().for_each(|field| {
+                if let Some(schema) = field_schema(db, field.r#type()) {
+                    if field.arity().is_required() && field.default_value().is_none() {
+                        required.push(field.name());
+                    }
+                    properties.insert(
+                        field.name().to_string(),
+                        with_documentation(
+                            with_arity(schema, field.arity()),
+                            field.documentation(),
+                        ),
+                    );
+                }
+            });
+            definitions.insert(
+                composite_type.name().to_string(),
+                object(
+                    composite_type.ast_composite_type().documentation(),
+                    properties,
+                    required,
+                ),
+            );
+        });
+    db.walk_models()
+        .chain(db.walk_views())
+        .filter(|model| !model.is_ignored() && kept.model(*model))
+        .for_each(|walker| {
+            definitions.insert(walker.name().to_string(), model(kept, walker));
+        });
+    json!({ "$schema": DRAFT, "$defs": definitions })
+}
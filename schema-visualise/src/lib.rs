@@ -1,31 +1,97 @@
 pub mod attributes;
 mod constraints;
+mod dbml;
+pub mod diff;
+mod docs;
+mod dot;
 pub mod field_type;
+mod graphql;
+pub mod highlight;
+pub mod import;
+mod json_schema;
+mod mermaid;
+mod plantuml;
 mod relations;
+mod rust;
+pub mod selection;
+mod site;
+pub mod source;
+pub mod sql;
+mod svg;
+pub mod typescript;
+pub mod validation;
 
-pub use crate::{attributes::ModelAttributes, constraints::Contraints, relations::RelationShips};
+pub use crate::{
+    attributes::ModelAttributes,
+    constraints::Contraints,
+    relations::RelationShips,
+    selection::{Kept, Selection},
+    source::{SchemaFile, SourceMap},
+    validation::{SchemaDiagnostic, Severity},
+};
 use field_type::PrismaVizFieldType;
 use prettytable::row;
 use prettytable::Table;
 use psl_core::diagnostics::Span;
+use psl_core::parser_database::walkers::{FieldWalker, ModelWalker, RefinedFieldWalker};
+use psl_core::schema_ast::ast::FieldArity;
 use psl_core::schema_ast::ast::FieldType;
-use psl_core::schema_ast::ast::WithIdentifier;
-use psl_core::{
-    diagnostics::Diagnostics,
-    schema_ast::{self, ast::SchemaAst},
-};
+use psl_core::schema_ast::ast::Top;
+use psl_core::schema_ast::ast::WithSpan;
+use psl_core::{diagnostics::Diagnostics, schema_ast::ast};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+use std::fmt;
+
+pub(crate) fn serialize_span<S: Serializer>(span: &Span, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut state = serializer.serialize_struct("Span", 2)?;
+    state.serialize_field("start", &span.start)?;
+    state.serialize_field("end", &span.end)?;
+    state.end()
+}
+
+fn serialize_arity<S: Serializer>(arity: &FieldArity, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(match arity {
+        FieldArity::Required => "required",
+        FieldArity::Optional => "optional",
+        FieldArity::List => "list",
+    })
+}
 
+/// `db.VarChar(200)` style rendering of a `@db.*` native type attribute
+fn native_type_string((datasource, name, args, _): (&str, &str, &[String], Span)) -> String {
+    match args.is_empty() {
+        true => format!("{datasource}.{name}"),
+        false => format!("{datasource}.{name}({})", args.join(", ")),
+    }
+}
+
+#[derive(Serialize)]
 pub struct PrismaVizModelField {
+    #[serde(skip)]
     pub attributes: ModelAttributes,
+    #[serde(flatten)]
     pub relation_ships: RelationShips,
     pub constraints: Contraints,
     pub name: String,
     pub r#type: PrismaVizFieldType,
+    #[serde(serialize_with = "serialize_arity")]
+    pub arity: FieldArity,
     pub is_index: String,
+    /// Name in the database, as given by `@map`
+    pub database_name: String,
+    /// Native type given through `@db.*`, if any
+    pub native_type: Option<String>,
+    /// Expression passed to `@default`, if any
+    pub default: Option<String>,
 }
 
 impl PrismaVizModelField {
-    fn new(name: String, data_type: PrismaVizFieldType, is_index: &str) -> PrismaVizModelField {
+    fn new(
+        name: String,
+        data_type: PrismaVizFieldType,
+        arity: FieldArity,
+        is_index: &str,
+    ) -> PrismaVizModelField {
         PrismaVizModelField {
             attributes: ModelAttributes { values: vec![] },
             relation_ships: RelationShips {
@@ -36,118 +102,430 @@ impl PrismaVizModelField {
             constraints: Contraints {
                 constraints: vec![],
             },
-            name,
             r#type: data_type,
+            database_name: name.clone(),
+            name,
+            arity,
             is_index: is_index.to_string(),
+            native_type: None,
+            default: None,
+        }
+    }
+    pub fn is_id(&self) -> bool {
+        self.has_constraint("id")
+    }
+    pub fn is_unique(&self) -> bool {
+        self.has_constraint("unique")
+    }
+    fn has_constraint(&self, name: &str) -> bool {
+        self.constraints.constraints.iter().any(|c| c.name == name)
+            || self
+                .attributes
+                .constraint_strings(&self.name)
+                .lines()
+                .any(|c| c == name)
+    }
+    /// Name given through `@relation("name")` or `@relation(name: "name")`, if any
+    pub fn relation_name(&self) -> Option<String> {
+        self.constraints
+            .constraints
+            .iter()
+            .find(|c| c.name == "relation")
+            .and_then(|c| c.argument.first())
+            .map(|arg| match arg {
+                constraints::ArgumentType::String(v)
+                | constraints::ArgumentType::Numeric(v)
+                | constraints::ArgumentType::FunctionCall(v) => v.clone(),
+            })
+    }
+}
+/// The kind of top level declaration a `PrismaVizModel` was built from
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrismaVizModelKind {
+    Model,
+    View,
+    Enum,
+    CompositeType,
+}
+
+impl PrismaVizModelKind {
+    /// Models and views are the declarations that take part in relations
+    pub fn is_model_like(&self) -> bool {
+        matches!(self, PrismaVizModelKind::Model | PrismaVizModelKind::View)
+    }
+}
+
+impl fmt::Display for PrismaVizModelKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PrismaVizModelKind::Model => write!(f, "Model"),
+            PrismaVizModelKind::View => write!(f, "View"),
+            PrismaVizModelKind::Enum => write!(f, "Enum"),
+            PrismaVizModelKind::CompositeType => write!(f, "Type"),
         }
     }
 }
+
+#[derive(Serialize)]
 pub struct PrismaVizModel {
     pub name: String,
+    pub kind: PrismaVizModelKind,
+    /// Name in the database, as given by `@@map`
+    pub database_name: String,
     pub fields: Vec<PrismaVizModelField>,
     pub code: String,
+    #[serde(serialize_with = "serialize_span")]
     pub span: Span,
+    /// File the declaration is in, when the schema was read from files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
+/// A model as `result` lists it, with an `id` that is new every time it is serialized
+#[derive(Serialize)]
+struct IdentifiedModel<'a> {
+    id: String,
+    #[serde(flatten)]
+    model: &'a PrismaVizModel,
 }
 
 impl PrismaVizModel {
-    pub fn new(name: String, code: String, span: Span) -> PrismaVizModel {
+    pub fn new(name: String, code: String, span: Span, kind: PrismaVizModelKind) -> PrismaVizModel {
         PrismaVizModel {
+            database_name: name.clone(),
             name,
+            kind,
             fields: vec![],
             code,
             span,
+            file: None,
+        }
+    }
+    /// Scalar fields used as `fields` in any of the model's `@relation` attributes
+    pub fn foreign_keys(&self) -> Vec<String> {
+        self.fields
+            .iter()
+            .flat_map(|f| f.relation_ships.fields())
+            .collect()
+    }
+    /// `PK`, `FK` and `UK` markers that apply to a field of this model
+    pub fn key_markers(&self, field: &PrismaVizModelField) -> Vec<&'static str> {
+        let mut keys = vec![];
+        if field.is_id() {
+            keys.push("PK");
+        }
+        if self.foreign_keys().contains(&field.name) {
+            keys.push("FK");
         }
+        if field.is_unique() {
+            keys.push("UK");
+        }
+        keys
     }
 }
 
 pub struct SchemaVisualiser {
+    /// The schema, the files it was read from concatenated
     pub schema: String,
+    /// Where the files the schema was read from sit in it, empty for a schema given as a string
+    pub files: SourceMap,
     pub models: Vec<PrismaVizModel>,
+    /// Errors and warnings from validating the schema with the builtin connectors
+    pub diagnostics: Diagnostics,
+    /// The schema as last validated by [`Self::parse`], kept for the renderers built on the
+    /// walkers when it has no errors
+    validated: Option<psl::ValidatedSchema>,
+    /// What the renderers show of the schema, see [`Self::select`]
+    selection: Selection,
+    /// What [`Self::selection`] keeps of the schema as last validated
+    kept: Kept,
+    /// Models [`Self::selection`] names that the schema as last validated does not have
+    unknown_models: Vec<String>,
+    /// The schema as last parsed, which the renderers parse again only when it changed
+    parsed: Option<String>,
+}
+
+/// Serializes to the same document `/api/v1/visualise` responds with, spans being offsets into
+/// `schema` that name their `file` when there are `files`
+impl Serialize for SchemaVisualiser {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("SchemaVisualiser", 4)?;
+        state.serialize_field("schema", &self.schema)?;
+        match self.files.is_empty() {
+            true => state.skip_field("files")?,
+            false => state.serialize_field("files", &self.files)?,
+        }
+        let result: Vec<IdentifiedModel> = self
+            .models
+            .iter()
+            .map(|model| IdentifiedModel {
+                id: uuid::Uuid::new_v4().to_string(),
+                model,
+            })
+            .collect();
+        state.serialize_field("result", &result)?;
+        state.serialize_field("diagnostics", &self.schema_diagnostics())?;
+        state.end()
+    }
 }
 
 impl SchemaVisualiser {
     pub fn new(contents: String) -> SchemaVisualiser {
         SchemaVisualiser {
             schema: contents,
+            files: SourceMap::default(),
             models: vec![],
+            diagnostics: Diagnostics::default(),
+            validated: None,
+            selection: Selection::default(),
+            kept: Kept::default(),
+            unknown_models: vec![],
+            parsed: None,
         }
     }
+    /// A schema split over `files`, parsed as one so that declarations refer across files
+    pub fn from_files(files: &[SchemaFile]) -> SchemaVisualiser {
+        let (schema, source_map) = SourceMap::concat(files);
+        SchemaVisualiser {
+            files: source_map,
+            ..SchemaVisualiser::new(schema)
+        }
+    }
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.has_errors()
+    }
+    /// Errors followed by warnings, spans in the concatenated schema
+    pub fn schema_diagnostics(&self) -> Vec<SchemaDiagnostic> {
+        let mut diagnostics = validation::collect(&self.diagnostics);
+        diagnostics.iter_mut().for_each(|diagnostic| {
+            diagnostic.file = self
+                .files
+                .locate(diagnostic.span)
+                .map(|(name, _)| name.to_string());
+        });
+        diagnostics
+    }
+    /// Errors followed by warnings, pretty printed against `file_name`, or against the file each
+    /// is in when the schema was read from files
+    pub fn diagnostics_to_pretty_string(&self, file_name: &str) -> String {
+        if self.files.is_empty() {
+            return self.diagnostics.to_pretty_string(file_name, &self.schema)
+                + &self
+                    .diagnostics
+                    .warnings_to_pretty_string(file_name, &self.schema);
+        }
+        let per_file = self.files.split(&self.schema, &self.diagnostics);
+        let errors: String = per_file
+            .iter()
+            .map(|(name, contents, diagnostics)| diagnostics.to_pretty_string(name, contents))
+            .collect();
+        let warnings: String = per_file
+            .iter()
+            .map(|(name, contents, diagnostics)| {
+                diagnostics.warnings_to_pretty_string(name, contents)
+            })
+            .collect();
+        errors + &warnings
+    }
     pub fn get_models(self) -> Vec<PrismaVizModel> {
         self.models
     }
     pub fn parse(&mut self) {
-        let mut diagnostics = Diagnostics::default();
-        let result: SchemaAst = schema_ast::parse_schema(self.schema.as_str(), &mut diagnostics);
-        let model_fields = result
+        self.models.clear();
+        self.validated = None;
+        self.kept = Kept::default();
+        self.parsed = Some(self.schema.clone());
+        let mut validated = psl::validate(self.schema.clone().into());
+        self.diagnostics = std::mem::take(&mut validated.diagnostics);
+        let db = &validated.db;
+        self.unknown_models = self.selection.unknown_models(db);
+        // The walkers expect every name and type to be resolved, so invalid schemas only get
+        // what can be read off the AST.
+        let resolved = !self.diagnostics.has_errors();
+        // An invalid schema is shown whole, as what a selection keeps is found through the walkers
+        let kept = match resolved {
+            true => Kept::new(db, &self.selection),
+            false => Kept::default(),
+        };
+        for (top_id, top) in db
+            .ast()
             .iter_tops()
-            .filter(|(_, top)| top.get_type() == "model")
-            .map(|(_, top)| top.as_model())
-            .flatten()
-            .map(|m| {
-                (
-                    m.identifier().name.clone(),
-                    m.iter_fields(),
-                    m.attributes.clone(),
-                    m.span.clone(),
-                )
-            });
-        model_fields.for_each(|(model, fields, attributes, span)| {
-            let code = &self.schema.as_str()[span.start..span.end];
-            let mut prisma_viz_model = PrismaVizModel::new(model, String::from(code), span);
-
-            let mut model_attributes = ModelAttributes::new();
-            model_attributes.populate(&attributes);
-            fields.for_each(|(_, field)| {
-                let model_attributes = model_attributes.to_owned();
-
-                let mut constraints = Contraints::new();
-                constraints.populate(&field.attributes);
-                let mut relationships = RelationShips::new();
-                relationships.populate(&field.attributes);
-
-                let mut field_type = PrismaVizFieldType::new();
-                match &field.field_type {
-                    FieldType::Unsupported(t, _) => {
-                        format!("Field type {}", t);
-                        field_type.resolve_data_type(t.clone(), "".to_string());
+            .filter(|(top_id, _)| kept.contains(*top_id))
+        {
+            let span = top.span();
+            let code = String::from(&self.schema[span.start..span.end]);
+            let name = top.name().to_string();
+            match top {
+                Top::Model(model) => {
+                    let kind = match model.is_view() {
+                        true => PrismaVizModelKind::View,
+                        false => PrismaVizModelKind::Model,
+                    };
+                    let mut prisma_viz_model = PrismaVizModel::new(name, code, span, kind);
+                    match top_id.as_model_id().filter(|_| resolved) {
+                        Some(model_id) => {
+                            self.walk_model(&mut prisma_viz_model, db.walk(model_id), &kept)
+                        }
+                        None => model.iter_fields().for_each(|(_, field)| {
+                            prisma_viz_model
+                                .fields
+                                .push(self.parse_field(field, ModelAttributes::new()));
+                        }),
                     }
-                    FieldType::Supported(t) => {
-                        let name = t.name.to_string();
-                        let len = name.len();
-                        let mut modifier =
-                            String::from(&self.schema[t.span.start + len..t.span.end + 2]);
-                        if modifier != String::from("[]") {
-                            modifier = String::from("");
+                    self.models.push(prisma_viz_model);
+                }
+                Top::CompositeType(composite_type) => {
+                    let mut prisma_viz_model =
+                        PrismaVizModel::new(name, code, span, PrismaVizModelKind::CompositeType);
+                    match top_id.as_composite_type_id().filter(|_| resolved) {
+                        Some(composite_type_id) => {
+                            db.walk(composite_type_id).fields().for_each(|field| {
+                                let mut prisma_viz_model_field =
+                                    self.parse_field(field.ast_field(), ModelAttributes::new());
+                                prisma_viz_model_field.database_name =
+                                    field.database_name().to_owned();
+                                prisma_viz_model_field.native_type =
+                                    field.raw_native_type().map(native_type_string);
+                                prisma_viz_model_field.default =
+                                    field.default_value().map(|value| value.to_string());
+                                prisma_viz_model.fields.push(prisma_viz_model_field);
+                            })
                         }
-                        field_type.resolve_data_type(name, modifier);
+                        None => composite_type.iter_fields().for_each(|(_, field)| {
+                            prisma_viz_model
+                                .fields
+                                .push(self.parse_field(field, ModelAttributes::new()));
+                        }),
                     }
-                };
+                    self.models.push(prisma_viz_model);
+                }
+                Top::Enum(r#enum) => {
+                    let mut prisma_viz_model =
+                        PrismaVizModel::new(name, code, span, PrismaVizModelKind::Enum);
+                    let enum_walker = top_id
+                        .as_enum_id()
+                        .filter(|_| resolved)
+                        .map(|enum_id| db.walk(enum_id));
+                    if let Some(enum_walker) = enum_walker {
+                        prisma_viz_model.database_name = enum_walker.database_name().to_owned();
+                    }
+                    r#enum.values.iter().enumerate().for_each(|(idx, value)| {
+                        let mut field = PrismaVizModelField::new(
+                            value.name.name.clone(),
+                            PrismaVizFieldType::enum_value(),
+                            FieldArity::Required,
+                            "false",
+                        );
+                        field.constraints.populate(&value.attributes);
+                        if let Some(value) = enum_walker.and_then(|e| e.values().nth(idx)) {
+                            field.database_name = value.database_name().to_owned();
+                        }
+                        prisma_viz_model.fields.push(field);
+                    });
+                    self.models.push(prisma_viz_model);
+                }
+                Top::Source(_) | Top::Generator(_) => {}
+            }
+        }
+        let files = &self.files;
+        self.models.iter_mut().for_each(|model| {
+            model.file = files.locate(model.span).map(|(name, _)| name.to_string());
+        });
+        if resolved {
+            self.validated = Some(validated);
+            self.kept = kept;
+        }
+    }
+    /// Fills in the fields of a model or view from its `ModelWalker`, leaving out the relation
+    /// fields to models `kept` drops
+    fn walk_model(
+        &self,
+        prisma_viz_model: &mut PrismaVizModel,
+        model: ModelWalker<'_>,
+        kept: &Kept,
+    ) {
+        prisma_viz_model.database_name = model.database_name().to_owned();
+        let mut model_attributes = ModelAttributes::new();
+        model_attributes.populate(model);
+        let is_kept = |field: &FieldWalker<'_>| match field.refine() {
+            RefinedFieldWalker::Relation(relation_field) => kept.relation_field(relation_field),
+            RefinedFieldWalker::Scalar(_) => true,
+        };
+        model.fields().filter(is_kept).for_each(|field| {
+            let mut prisma_viz_model_field =
+                self.parse_field(field.ast_field(), model_attributes.to_owned());
+            match field.refine() {
+                RefinedFieldWalker::Scalar(scalar_field) => {
+                    prisma_viz_model_field.database_name = scalar_field.database_name().to_owned();
+                    prisma_viz_model_field.native_type =
+                        scalar_field.raw_native_type().map(native_type_string);
+                    prisma_viz_model_field.default = scalar_field
+                        .default_value()
+                        .map(|default| default.value().to_string());
+                }
+                RefinedFieldWalker::Relation(relation_field) => {
+                    prisma_viz_model_field
+                        .relation_ships
+                        .resolve(relation_field);
+                }
+            }
+            prisma_viz_model.fields.push(prisma_viz_model_field);
+        });
+    }
+    fn parse_field(
+        &self,
+        field: &ast::Field,
+        model_attributes: ModelAttributes,
+    ) -> PrismaVizModelField {
+        let mut constraints = Contraints::new();
+        constraints.populate(&field.attributes);
+        let mut relationships = RelationShips::new();
+        relationships.populate(&field.attributes);
 
-                let is_index = model_attributes.is_index(field.name());
+        let modifier = match field.arity {
+            FieldArity::Required => "",
+            FieldArity::Optional => "?",
+            FieldArity::List => "[]",
+        };
+        let mut field_type = PrismaVizFieldType::new();
+        match &field.field_type {
+            FieldType::Unsupported(t, _) => {
+                field_type.resolve_data_type(
+                    format!("Unsupported({})", psl_core::schema_ast::string_literal(t)),
+                    modifier.to_string(),
+                );
+            }
+            FieldType::Supported(t) => {
+                field_type.resolve_data_type(t.name.to_string(), modifier.to_string());
+            }
+        };
 
-                let mut prisma_vis_model_field =
-                    PrismaVizModelField::new(field.name().to_string(), field_type, is_index);
-                prisma_vis_model_field.relation_ships = relationships;
-                prisma_vis_model_field.constraints = constraints;
-                prisma_vis_model_field.attributes = model_attributes;
-                prisma_viz_model.fields.push(prisma_vis_model_field);
-            });
+        let is_index = model_attributes.is_index(field.name());
 
-            self.models.push(prisma_viz_model);
-        });
+        let mut prisma_vis_model_field =
+            PrismaVizModelField::new(field.name().to_string(), field_type, field.arity, is_index);
+        prisma_vis_model_field.relation_ships = relationships;
+        prisma_vis_model_field.constraints = constraints;
+        prisma_vis_model_field.attributes = model_attributes;
+        prisma_vis_model_field
     }
     pub fn print_as_table(&mut self) {
-        self.parse();
+        self.parse_if_changed();
         self.models.iter().for_each(|model| {
-            println!("Model {}", model.name);
+            println!("{} {}", model.kind, model.name);
             let mut table: Table = Table::new();
             table.add_row(row![
                 "Name",
                 "Type",
                 "Attributes_Constraints",
+                "Relation",
                 "Relation_Fields",
                 "Relation_References",
                 "Index",
+                "Database_Name",
+                "Native_Type",
+                "Default",
             ]);
 
             model.fields.iter().for_each(|field| {
@@ -162,14 +540,128 @@ impl SchemaVisualiser {
                     field.name,
                     field_type,
                     constraint_strings,
+                    field.relation_ships.summary(),
                     field.relation_ships.fields().join("\n"),
                     field.relation_ships.references().join("\n"),
                     field.is_index,
+                    field.database_name,
+                    field.native_type.as_deref().unwrap_or_default(),
+                    field.default.as_deref().unwrap_or_default(),
                 ]);
             });
 
             table.printstd();
-            println!("");
+            println!();
         })
     }
+    /// Renders the schema as a Mermaid `erDiagram`, ready to be pasted into markdown
+    pub fn render_mermaid(&mut self) -> String {
+        self.parse_if_changed();
+        mermaid::render(&self.models)
+    }
+    /// Renders the schema as a Graphviz `digraph`, to be laid out with `dot -Tsvg`
+    pub fn render_dot(&mut self) -> String {
+        self.parse_if_changed();
+        dot::render(&self.models)
+    }
+    /// Renders the schema as a PlantUML entity diagram, primary keys above the separator
+    pub fn render_plantuml(&mut self) -> String {
+        self.parse_if_changed();
+        plantuml::render(&self.models)
+    }
+    /// Renders the schema as a standalone SVG diagram, laid out without Graphviz
+    pub fn render_svg(&mut self) -> String {
+        self.parse_if_changed();
+        svg::render(&self.models)
+    }
+    /// Source of the declaration called `name`, coloured for a terminal
+    pub fn render_source(&mut self, name: &str) -> Option<String> {
+        self.parse_if_changed();
+        let span = self.models.iter().find(|model| model.name == name)?.span;
+        Some(highlight::Highlighter::new(&self.schema).ansi_for(span))
+    }
+    /// Renders the whole schema as syntax highlighted HTML
+    pub fn render_html(&self) -> String {
+        highlight::Highlighter::new(&self.schema).html()
+    }
+    /// Parses the schema unless it is the one last parsed, which every renderer starts with
+    fn parse_if_changed(&mut self) {
+        if self.parsed.as_ref() != Some(&self.schema) {
+            self.parse();
+        }
+    }
+    /// Parses the schema if it changed, keeping what it validated to and what the selection
+    /// keeps of it for the renderers built on the walkers
+    fn validated(&mut self) -> Option<(&psl::ValidatedSchema, &Kept)> {
+        self.parse_if_changed();
+        self.validated.as_ref().map(|schema| (schema, &self.kept))
+    }
+    /// Focuses every renderer on what `selection` keeps, the schema itself is left as it is. A
+    /// schema that does not validate is shown whole
+    pub fn select(&mut self, selection: &Selection) {
+        self.selection = selection.clone();
+        self.parse();
+    }
+    /// Models the selection starts from that are no model or view of the schema
+    pub fn unknown_models(&self) -> &[String] {
+        &self.unknown_models
+    }
+    /// CREATE statements for `dialect`, empty when the schema does not validate
+    pub fn render_sql(&mut self, dialect: sql::Dialect) -> String {
+        self.validated()
+            .map(|(schema, kept)| sql::render(schema, kept, dialect))
+            .unwrap_or_default()
+    }
+    /// TypeScript declarations, empty when the schema does not validate
+    pub fn render_typescript(&mut self, options: &typescript::TypescriptOptions) -> String {
+        self.validated()
+            .map(|(schema, kept)| typescript::render(&schema.db, kept, options))
+            .unwrap_or_default()
+    }
+    /// JSON Schema document with a `$defs` entry per model, view and composite type,
+    /// `None` when the schema does not validate
+    pub fn json_schema(&mut self) -> Option<serde_json::Value> {
+        self.validated()
+            .map(|(schema, kept)| json_schema::render(&schema.db, kept))
+    }
+    /// Renders [`Self::json_schema`] pretty printed, empty when the schema does not validate
+    pub fn render_json_schema(&mut self) -> String {
+        self.json_schema()
+            .map(|schema| serde_json::to_string_pretty(&schema).expect("Failed to render JSON."))
+            .unwrap_or_default()
+    }
+    /// Markdown data dictionary with the `///` comments, empty when the schema does not validate
+    pub fn render_docs(&mut self) -> String {
+        self.validated()
+            .map(|(schema, kept)| docs::render(&schema.db, kept))
+            .unwrap_or_default()
+    }
+    /// DBML tables, enums and references, empty when the schema does not validate
+    pub fn render_dbml(&mut self) -> String {
+        self.validated()
+            .map(|(schema, kept)| dbml::render(schema, kept))
+            .unwrap_or_default()
+    }
+    /// GraphQL type definitions, empty when the schema does not validate
+    pub fn render_graphql(&mut self) -> String {
+        self.validated()
+            .map(|(schema, kept)| graphql::render(&schema.db, kept))
+            .unwrap_or_default()
+    }
+    /// Serde structs and enums for the tables, empty when the schema does not validate
+    pub fn render_rust(&mut self) -> String {
+        self.validated()
+            .map(|(schema, kept)| rust::render(&schema.db, kept))
+            .unwrap_or_default()
+    }
+    /// Renders the parsed models as pretty printed JSON
+    pub fn render_json(&mut self) -> String {
+        self.parse_if_changed();
+        serde_json::to_string_pretty(self).expect("Failed to render JSON.")
+    }
+    /// Files of the static inspector site as `(path, contents)`, diagnostics included
+    pub fn render_site(&mut self) -> Vec<(&'static str, String)> {
+        self.parse_if_changed();
+        site::render(self)
+    }
 }
@@ -1,25 +1,487 @@
-use clap::Parser;
+use std::{io::IsTerminal, time::Duration};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// One table per model
+    Table,
+    /// Mermaid `erDiagram`
+    Mermaid,
+    /// Graphviz DOT graph
+    Dot,
+    /// PlantUML entity diagram
+    Plantuml,
+    /// Standalone SVG diagram, laid out without Graphviz
+    Svg,
+    /// The same JSON document `/api/v1/visualise` responds with
+    Json,
+    /// The schema source as syntax highlighted HTML
+    Html,
+    /// TypeScript interfaces for models, views and composite types, unions for enums
+    Typescript,
+    /// Serde structs for models, views and composite types, Rust enums for enums
+    Rust,
+    /// JSON Schema (draft 2020-12) with a definition per model and composite type
+    JsonSchema,
+    /// GraphQL object types, enums and custom scalars
+    Graphql,
+    /// DBML tables, enums, indexes and references for dbdiagram.io and friends
+    Dbml,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiffFormat {
+    /// One row per change, coloured by kind
+    Table,
+    /// The changes and the diagnostics of both schemas
+    Json,
+    /// A table to paste into a pull request comment
+    Markdown,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Dialect {
+    Postgres,
+    Cockroachdb,
+    Mysql,
+    Sqlserver,
+    Sqlite,
+}
+
+impl From<Dialect> for sql::Dialect {
+    fn from(dialect: Dialect) -> sql::Dialect {
+        match dialect {
+            Dialect::Postgres => sql::Dialect::Postgres,
+            Dialect::Cockroachdb => sql::Dialect::Cockroachdb,
+            Dialect::Mysql => sql::Dialect::Mysql,
+            Dialect::Sqlserver => sql::Dialect::Sqlserver,
+            Dialect::Sqlite => sql::Dialect::Sqlite,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the source of one model, view, enum or type with syntax highlighting
+    Show {
+        /// Name of the declaration to print
+        name: String,
+        /// Leave out terminal colours, the default when stdout is not a terminal
+        #[arg(long)]
+        no_color: bool,
+    },
+    /// Compare two schemas and list what was added, removed, renamed or modified
+    Diff {
+        /// Path to the schema before the change, or to a directory of `.prisma` files. Separate
+        /// several paths with commas for a schema split over several files
+        #[arg(required = true, num_args = 1, value_delimiter = ',')]
+        old: Vec<String>,
+        /// Path to the schema after the change, like the one before it
+        #[arg(required = true, num_args = 1, value_delimiter = ',')]
+        new: Vec<String>,
+        /// Output format of the changes
+        #[arg(long, value_enum, default_value_t = DiffFormat::Table)]
+        format: DiffFormat,
+    },
+    /// Print the CREATE TABLE, INDEX and FOREIGN KEY statements the schema implies
+    Sql {
+        /// Database to write the statements for
+        #[arg(long, value_enum, default_value_t = Dialect::Postgres)]
+        dialect: Dialect,
+    },
+    /// Write a Markdown data dictionary of the models, views and enums, doc comments included
+    Docs {
+        /// File to write the dictionary to, stdout when left out
+        #[arg(long)]
+        out: Option<String>,
+    },
+    /// Write a static inspector site that works without the API, from `file://` too
+    Site {
+        /// Directory to write the site to, created when missing
+        #[arg(long)]
+        out: String,
+    },
+    /// Build a schema out of the CREATE statements in a SQL dump
+    Import {
+        /// Path to the `.sql` file
+        ddl: String,
+        /// Database the dump was taken from
+        #[arg(long, value_enum, default_value_t = Dialect::Postgres)]
+        dialect: Dialect,
+    },
+}
 
 #[derive(Parser)]
 #[command(author,version,about,long_about = None)]
 struct Args {
-    /// Optional file arugment indicating the full path to your 'primsa.schema' file
+    /// Path to your `schema.prisma`, or to a directory of `.prisma` files. Repeat it for a schema
+    /// split over several files
+    #[arg(long, global = true)]
+    file: Vec<String>,
+    /// Output format of the visualisation [default: table, or what the `--output` extension
+    /// implies]
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+    /// Write the visualisation to this file instead of stdout, e.g. `diagram.svg`
+    #[arg(long)]
+    output: Option<String>,
+    /// Render again whenever a schema file changes, until interrupted
     #[arg(long)]
-    file: Option<String>,
+    watch: bool,
+    /// TypeScript type of `Decimal` fields
+    #[arg(long, default_value = "string")]
+    ts_decimal: String,
+    /// TypeScript type of `BigInt` fields
+    #[arg(long, default_value = "bigint")]
+    ts_bigint: String,
+    /// TypeScript type of `Json` fields
+    #[arg(long, default_value = "unknown")]
+    ts_json: String,
+    /// TypeScript type of `Bytes` fields
+    #[arg(long, default_value = "Uint8Array")]
+    ts_bytes: String,
+    /// Only show this model or view and the models around it, repeat it for several
+    #[arg(long, global = true)]
+    model: Vec<String>,
+    /// How many relations away from `--model` models are still shown
+    #[arg(long, global = true, default_value_t = 1)]
+    depth: usize,
+    /// Only show models and views whose name matches this pattern, `*` and `?` being wildcards.
+    /// Repeat it for several
+    #[arg(long, global = true)]
+    include: Vec<String>,
+    /// Hide models and views whose name matches this pattern. Repeat it for several
+    #[arg(long, global = true)]
+    exclude: Vec<String>,
+    #[command(subcommand)]
+    command: Option<Command>,
 }
 
-use prismaviz::SchemaVisualiser;
+use prismaviz::{
+    diff::SchemaDiff, import::import as import_ddl, source, sql, typescript::TypescriptOptions,
+    SchemaVisualiser, Selection, SourceMap,
+};
 
 fn main() {
     let args = Args::parse();
-    match args.file {
-        None => {
-            panic!("Err!!!You forgot to pass a path to a file");
+    match args.command {
+        Some(Command::Diff { old, new, format }) => diff(&old, &new, format),
+        Some(Command::Import { ddl, dialect }) => import(&ddl, dialect),
+        command => {
+            let options = TypescriptOptions {
+                decimal: args.ts_decimal,
+                bigint: args.ts_bigint,
+                json: args.ts_json,
+                bytes: args.ts_bytes,
+            };
+            let format = args
+                .format
+                .or_else(|| args.output.as_deref().and_then(format_for_output))
+                .unwrap_or(Format::Table);
+            let selection = Selection {
+                models: args.model,
+                depth: args.depth,
+                include: args.include,
+                exclude: args.exclude,
+            };
+            visualise(
+                args.file,
+                format,
+                args.output,
+                &options,
+                &selection,
+                command,
+                args.watch,
+            )
+        }
+    }
+}
+
+/// The format a file name implies through its extension
+fn format_for_output(output: &str) -> Option<Format> {
+    match std::path::Path::new(output).extension()?.to_str()? {
+        "svg" => Some(Format::Svg),
+        "mmd" => Some(Format::Mermaid),
+        "dot" | "gv" => Some(Format::Dot),
+        "puml" => Some(Format::Plantuml),
+        "json" => Some(Format::Json),
+        "html" => Some(Format::Html),
+        "ts" => Some(Format::Typescript),
+        "rs" => Some(Format::Rust),
+        "graphql" => Some(Format::Graphql),
+        "dbml" => Some(Format::Dbml),
+        _ => None,
+    }
+}
+
+/// Everything but the table renders to a string that can go to a file
+fn render(
+    visualiser: &mut SchemaVisualiser,
+    format: Format,
+    options: &TypescriptOptions,
+) -> Option<String> {
+    match format {
+        Format::Table => None,
+        Format::Mermaid => Some(visualiser.render_mermaid()),
+        Format::Dot => Some(visualiser.render_dot()),
+        Format::Plantuml => Some(visualiser.render_plantuml()),
+        Format::Svg => Some(visualiser.render_svg()),
+        Format::Json => Some(visualiser.render_json() + "\n"),
+        Format::Html => Some(visualiser.render_html() + "\n"),
+        Format::Typescript => Some(visualiser.render_typescript(options)),
+        Format::Rust => Some(visualiser.render_rust()),
+        Format::JsonSchema => Some(visualiser.render_json_schema() + "\n"),
+        Format::Graphql => Some(visualiser.render_graphql()),
+        Format::Dbml => Some(visualiser.render_dbml()),
+    }
+}
+
+fn write_or_print(rendered: String, output: Option<String>) {
+    match output {
+        Some(output) => {
+            if let Err(error) = std::fs::write(&output, rendered) {
+                eprintln!("Failed to write {}: {}", output, error);
+                std::process::exit(1);
+            }
+        }
+        None => print!("{}", rendered),
+    }
+}
+
+fn write_site(files: Vec<(&'static str, String)>, out: &str) {
+    let out = std::path::Path::new(out);
+    let written = std::fs::create_dir_all(out).and_then(|_| {
+        files
+            .into_iter()
+            .try_for_each(|(path, contents)| std::fs::write(out.join(path), contents))
+    });
+    if let Err(error) = written {
+        eprintln!("Failed to write the site to {}: {}", out.display(), error);
+        std::process::exit(1);
+    }
+}
+
+fn visualise(
+    files: Vec<String>,
+    format: Format,
+    output: Option<String>,
+    options: &TypescriptOptions,
+    selection: &Selection,
+    command: Option<Command>,
+    watch: bool,
+) {
+    if files.is_empty() {
+        eprintln!("Pass the path to your schema with --file");
+        std::process::exit(1);
+    }
+    let render = || {
+        visualise_once(
+            &files,
+            format,
+            output.clone(),
+            options,
+            selection,
+            command.as_ref(),
+        )
+    };
+    if watch {
+        if let Err(error) = watch_files(&files, render) {
+            eprintln!("Failed to watch {}: {}", files.join(", "), error);
+            std::process::exit(1);
+        }
+    } else if !render() {
+        std::process::exit(1);
+    }
+}
+
+/// Reads and renders the schema, printing its diagnostics. False when the schema could not be
+/// read, has errors, or has nothing to show
+fn visualise_once(
+    paths: &[String],
+    format: Format,
+    output: Option<String>,
+    options: &TypescriptOptions,
+    selection: &Selection,
+    command: Option<&Command>,
+) -> bool {
+    let files = match source::read(paths) {
+        Ok(files) if files.is_empty() => {
+            eprintln!("There are no .prisma files in {}", paths.join(", "));
+            return false;
+        }
+        Ok(files) => files,
+        Err(error) => {
+            eprintln!("Failed to read the schema: {}", error);
+            return false;
+        }
+    };
+    // Parsed once here, the renderers reuse it
+    let mut visualiser = SchemaVisualiser::from_files(&files);
+    visualiser.select(selection);
+    if !visualiser.unknown_models().is_empty() {
+        eprintln!(
+            "There is no model or view called {}",
+            visualiser.unknown_models().join(", ")
+        );
+        return false;
+    }
+    let mut not_found = false;
+    match command {
+        Some(Command::Show { name, no_color }) => {
+            if *no_color || !std::io::stdout().is_terminal() {
+                colored::control::set_override(false);
+            }
+            match visualiser.render_source(name) {
+                Some(source) => println!("{}", source),
+                None => {
+                    eprintln!("There is no model, view, enum or type called {}", name);
+                    not_found = true;
+                }
+            }
+        }
+        Some(Command::Sql { dialect }) => {
+            print!("{}", visualiser.render_sql((*dialect).into()))
+        }
+        Some(Command::Docs { out }) => write_or_print(visualiser.render_docs(), out.clone()),
+        Some(Command::Site { out }) => write_site(visualiser.render_site(), out),
+        _ => match (render(&mut visualiser, format, options), output) {
+            (None, None) => visualiser.print_as_table(),
+            (None, Some(_)) => {
+                eprintln!("The table format can only be printed, pick another --format");
+                std::process::exit(1);
+            }
+            (Some(rendered), output) => write_or_print(rendered, output),
+        },
+    }
+    eprint!(
+        "{}",
+        visualiser.diagnostics_to_pretty_string(&paths.join(", "))
+    );
+    !not_found && !visualiser.has_errors()
+}
+
+/// Renders once, then again after every burst of writes to the schema files settles, until
+/// interrupted
+fn watch_files(
+    paths: &[String],
+    render: impl Fn() -> bool,
+) -> notify_debouncer_mini::notify::Result<()> {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut debouncer = new_debouncer(Duration::from_millis(200), sender)?;
+    paths.iter().try_for_each(|path| {
+        let path = std::path::Path::new(path);
+        // Editors often save by renaming a new file over the old one, so the directory of a
+        // file is watched, and files added to a watched directory are picked up
+        let (watched, mode) = match path.parent() {
+            _ if path.is_dir() => (path, RecursiveMode::Recursive),
+            Some(parent) if !parent.as_os_str().is_empty() => (parent, RecursiveMode::NonRecursive),
+            _ => (std::path::Path::new("."), RecursiveMode::NonRecursive),
+        };
+        debouncer.watcher().watch(watched, mode)
+    })?;
+    let rerender = || {
+        if std::io::stdout().is_terminal() {
+            // Clears the screen, so that only the latest render is visible
+            print!("\x1b[2J\x1b[H");
+        }
+        render();
+        eprintln!(
+            "Watching {} for changes, press Ctrl-C to stop",
+            paths.join(", ")
+        );
+    };
+    rerender();
+    // Other files in the watched directories, and bursts of writes straddling two debounce
+    // windows, change nothing that is rendered
+    let mut files = source::read(paths).ok();
+    for events in receiver {
+        events?;
+        let latest = source::read(paths).ok();
+        if latest != files {
+            files = latest;
+            rerender();
+        }
+    }
+    Ok(())
+}
+
+fn diff(old: &[String], new: &[String], format: DiffFormat) {
+    let (old_schema, old_files) = read_schema(old);
+    let (new_schema, new_files) = read_schema(new);
+    let diff = SchemaDiff::new(&old_schema, &new_schema);
+    match format {
+        DiffFormat::Table => match diff.cannot_diff() {
+            Some(reason) => println!("{}", reason),
+            None if diff.changes.is_empty() => println!("No changes."),
+            None => diff.print_as_table(),
+        },
+        DiffFormat::Json => println!("{}", diff.render_json()),
+        DiffFormat::Markdown => print!("{}", diff.render_markdown()),
+    }
+    [
+        (&old_schema, &old_files, &diff.old_diagnostics),
+        (&new_schema, &new_files, &diff.new_diagnostics),
+    ]
+    .into_iter()
+    .for_each(|(schema, files, diagnostics)| {
+        files
+            .split(schema, diagnostics)
+            .iter()
+            .for_each(|(name, contents, diagnostics)| {
+                eprint!("{}", diagnostics.to_pretty_string(name, contents))
+            })
+    });
+    if diff.has_errors() {
+        std::process::exit(1);
+    }
+}
+
+/// The files at `paths` concatenated into one schema, exiting when they cannot be read
+fn read_schema(paths: &[String]) -> (String, SourceMap) {
+    match source::read(paths) {
+        Ok(files) if files.is_empty() => {
+            eprintln!("There are no .prisma files in {}", paths.join(", "));
+            std::process::exit(1);
+        }
+        Ok(files) => SourceMap::concat(&files),
+        Err(error) => {
+            eprintln!("Failed to read the schema: {}", error);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn import(path: &str, dialect: Dialect) {
+    let ddl = match std::fs::read_to_string(path) {
+        Ok(ddl) => ddl,
+        Err(error) => {
+            eprintln!("Failed to read {}: {}", path, error);
+            std::process::exit(1);
         }
-        Some(v) => {
-            let contents = std::fs::read_to_string(&v).unwrap();
-            let mut visualiser = SchemaVisualiser::new(contents);
-            visualiser.print_as_table();
+    };
+    let schema = match import_ddl(&ddl, dialect.into()) {
+        Ok(schema) => schema,
+        Err(error) => {
+            print!("{}", error.schema);
+            eprint!(
+                "{}",
+                error
+                    .diagnostics
+                    .to_pretty_string("schema.prisma", &error.schema)
+            );
+            std::process::exit(1);
         }
+    };
+    let mut visualiser = SchemaVisualiser::new(schema);
+    visualiser.parse();
+    print!("{}", visualiser.schema);
+    eprint!(
+        "{}",
+        visualiser.diagnostics_to_pretty_string("schema.prisma")
+    );
+    if visualiser.has_errors() {
+        std::process::exit(1);
     }
 }
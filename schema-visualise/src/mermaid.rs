@@ -0,0 +1,60 @@
+use std::fmt::Write;
+
+use crate::{relations, PrismaVizModel};
+
+/// `data_type` as a Mermaid attribute type, which cannot hold anything but word characters:
+/// `Unsupported("point")` becomes `Unsupported_point`
+fn type_token(data_type: &str) -> String {
+    data_type
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn render_entity(out: &mut String, model: &PrismaVizModel, models: &[&PrismaVizModel]) {
+    writeln!(out, "    {} {{", model.name).unwrap();
+    model
+        .fields
+        .iter()
+        .filter(|f| !models.iter().any(|m| m.name == f.r#type.get_data_type()))
+        .for_each(|field| {
+            let keys = model.key_markers(field);
+            let mut data_type = type_token(&field.r#type.get_data_type());
+            if field.arity.is_list() {
+                data_type.push_str("[]");
+            }
+            write!(out, "        {} {}", data_type, field.name).unwrap();
+            if !keys.is_empty() {
+                write!(out, " {}", keys.join(", ")).unwrap();
+            }
+            out.push('\n');
+        });
+    out.push_str("    }\n");
+}
+
+fn render_relations(out: &mut String, model: &PrismaVizModel, models: &[&PrismaVizModel]) {
+    relations::edges(model, models).iter().for_each(|edge| {
+        writeln!(
+            out,
+            "    {} {}--{} {} : \"{}\"",
+            model.name, edge.left, edge.right, edge.target, edge.label
+        )
+        .unwrap();
+    });
+}
+
+pub(crate) fn render(models: &[PrismaVizModel]) -> String {
+    let models = models
+        .iter()
+        .filter(|m| m.kind.is_model_like())
+        .collect::<Vec<_>>();
+    let mut out = String::from("erDiagram\n");
+    models
+        .iter()
+        .for_each(|model| render_entity(&mut out, model, &models));
+    models
+        .iter()
+        .for_each(|model| render_relations(&mut out, model, &models));
+    out
+}
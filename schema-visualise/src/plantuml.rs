@@ -0,0 +1,68 @@
+use std::fmt::Write;
+
+use crate::{relations, PrismaVizModel, PrismaVizModelField, PrismaVizModelKind};
+
+fn render_field(
+    out: &mut String,
+    model: &PrismaVizModel,
+    marker: &str,
+    field: &PrismaVizModelField,
+) {
+    write!(
+        out,
+        "  {}{} : {}",
+        marker,
+        field.name,
+        field.r#type.resolve_with_modifier()
+    )
+    .unwrap();
+    model
+        .key_markers(field)
+        .iter()
+        .for_each(|key| write!(out, " <<{}>>", key).unwrap());
+    out.push('\n');
+}
+
+fn render_entity(out: &mut String, model: &PrismaVizModel, models: &[&PrismaVizModel]) {
+    write!(out, "entity {}", model.name).unwrap();
+    if model.kind == PrismaVizModelKind::View {
+        out.push_str(" <<view>>");
+    }
+    out.push_str(" {\n");
+    let (keys, fields): (Vec<_>, Vec<_>) = model
+        .fields
+        .iter()
+        .filter(|f| !models.iter().any(|m| m.name == f.r#type.get_data_type()))
+        .partition(|field| field.is_id());
+    keys.iter()
+        .for_each(|field| render_field(out, model, "*", field));
+    out.push_str("  --\n");
+    fields
+        .iter()
+        .for_each(|field| render_field(out, model, "", field));
+    out.push_str("}\n");
+}
+
+pub(crate) fn render(models: &[PrismaVizModel]) -> String {
+    let models = models
+        .iter()
+        .filter(|m| m.kind.is_model_like())
+        .collect::<Vec<_>>();
+    let mut out = String::from("@startuml\nhide circle\nskinparam linetype ortho\n\n");
+    models.iter().for_each(|model| {
+        render_entity(&mut out, model, &models);
+        out.push('\n');
+    });
+    models.iter().for_each(|model| {
+        relations::edges(model, &models).iter().for_each(|edge| {
+            writeln!(
+                out,
+                "{} {}--{} {} : {}",
+                model.name, edge.left, edge.right, edge.target, edge.label
+            )
+            .unwrap();
+        })
+    });
+    out.push_str("@enduml\n");
+    out
+}
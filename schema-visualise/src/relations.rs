@@ -1,4 +1,12 @@
-use psl_core::schema_ast::ast::{Attribute, Expression};
+use std::fmt;
+
+use psl_core::{
+    parser_database::walkers::{RefinedRelationWalker, RelationFieldWalker},
+    schema_ast::ast::{Attribute, Expression},
+};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::{PrismaVizModel, PrismaVizModelField};
 
 pub struct RelationalField {
     pub value: String,
@@ -11,14 +19,51 @@ pub enum Relations {
     Reference(RelationalReference),
 }
 
+#[derive(Clone, Debug, PartialEq)]
 pub enum RelationshipType {
     OneToOne,
     OneToMany,
     ManyToMany,
-    SelfRelation,
+    SelfRelation(Box<RelationshipType>),
     MayBe(Box<RelationshipType>),
     None,
 }
+
+impl RelationshipType {
+    /// Resolves the cardinality of the relation `field` is part of. Optional sides are
+    /// wrapped in `MayBe`, relations of a model with itself in `SelfRelation`.
+    pub fn resolve(field: RelationFieldWalker<'_>) -> RelationshipType {
+        let relation = field.relation();
+        let mut relationship_type = match relation.refine() {
+            RefinedRelationWalker::Inline(inline) if inline.is_one_to_one() => {
+                RelationshipType::OneToOne
+            }
+            RefinedRelationWalker::Inline(_) => RelationshipType::OneToMany,
+            RefinedRelationWalker::ImplicitManyToMany(_)
+            | RefinedRelationWalker::TwoWayEmbeddedManyToMany(_) => RelationshipType::ManyToMany,
+        };
+        if relation.is_self_relation() {
+            relationship_type = RelationshipType::SelfRelation(Box::new(relationship_type));
+        }
+        if field.ast_field().arity.is_optional() {
+            relationship_type = RelationshipType::MayBe(Box::new(relationship_type));
+        }
+        relationship_type
+    }
+}
+
+impl fmt::Display for RelationshipType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RelationshipType::OneToOne => write!(f, "1:1"),
+            RelationshipType::OneToMany => write!(f, "1:n"),
+            RelationshipType::ManyToMany => write!(f, "m:n"),
+            RelationshipType::SelfRelation(inner) => write!(f, "self {}", inner),
+            RelationshipType::MayBe(inner) => write!(f, "{}?", inner),
+            RelationshipType::None => Ok(()),
+        }
+    }
+}
 pub struct RelationShips {
     pub relations: Vec<Relations>,
     pub r#type: RelationshipType,
@@ -33,47 +78,49 @@ impl RelationShips {
             on: String::from(""),
         }
     }
-    pub fn populate(&mut self, attributes: &Vec<Attribute>) {
+    pub fn populate(&mut self, attributes: &[Attribute]) {
         attributes.iter().for_each(|attribute| {
             if attribute.name.name == "relation" {
-                attribute
-                    .arguments
-                    .arguments
-                    .iter()
-                    .for_each(|arg| match &arg.value {
-                        Expression::Array(v, _s) => {
-                            for exp in v.iter() {
-                                match exp {
-                                    Expression::ConstantValue(v, _s) => match &arg.name {
-                                        Some(n) => {
-                                            if n.name == "fields" {
-                                                self.relations.push(Relations::Field(
-                                                    RelationalField { value: v.clone() },
-                                                ));
-                                            }
-                                            if n.name == "references" {
-                                                self.relations.push(Relations::Reference(
-                                                    RelationalReference { value: v.clone() },
-                                                ));
-                                            }
-                                        }
-                                        None => {}
-                                    },
-                                    _ => {}
+                attribute.arguments.arguments.iter().for_each(|arg| {
+                    if let Expression::Array(v, _s) = &arg.value {
+                        for exp in v.iter() {
+                            if let Expression::ConstantValue(v, _s) = exp {
+                                if let Some(n) = &arg.name {
+                                    if n.name == "fields" {
+                                        self.relations.push(Relations::Field(RelationalField {
+                                            value: v.clone(),
+                                        }));
+                                    }
+                                    if n.name == "references" {
+                                        self.relations.push(Relations::Reference(
+                                            RelationalReference { value: v.clone() },
+                                        ));
+                                    }
                                 }
                             }
                         }
-                        _ => {}
-                    })
-            } else {
+                    }
+                })
             }
         })
     }
+    /// Fills in the cardinality and the target model of a relation field
+    pub fn resolve(&mut self, field: RelationFieldWalker<'_>) {
+        self.r#type = RelationshipType::resolve(field);
+        self.on = field.related_model().name().to_string();
+    }
+    /// Cardinality and target model, e.g. `1:n User`. Empty for scalar fields.
+    pub fn summary(&self) -> String {
+        match self.r#type {
+            RelationshipType::None => String::new(),
+            _ => format!("{} {}", self.r#type, self.on),
+        }
+    }
     pub fn fields(&self) -> Vec<String> {
         self.relations
             .iter()
             .map(|rel| match rel {
-                Relations::Field(rf) => String::from(rf.value.clone()),
+                Relations::Field(rf) => rf.value.clone(),
                 _ => String::from("none"),
             })
             .filter(|rel| rel != "none")
@@ -83,7 +130,7 @@ impl RelationShips {
         self.relations
             .iter()
             .map(|rel| match rel {
-                Relations::Reference(rr) => String::from(rr.value.clone()),
+                Relations::Reference(rr) => rr.value.clone(),
                 _ => String::from("none"),
             })
             .filter(|rel| rel != "none")
@@ -96,3 +143,92 @@ impl Default for RelationShips {
         Self::new()
     }
 }
+
+impl Serialize for RelationShips {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let relationship_type = match self.r#type {
+            RelationshipType::None => None,
+            _ => Some(self.r#type.to_string()),
+        };
+        let on = match self.on.is_empty() {
+            true => None,
+            false => Some(&self.on),
+        };
+        let mut state = serializer.serialize_struct("RelationShips", 4)?;
+        state.serialize_field("relation_ship_fields", &self.fields())?;
+        state.serialize_field("relation_ship_references", &self.references())?;
+        state.serialize_field("relation_ship_type", &relationship_type)?;
+        state.serialize_field("relation_ship_on", &on)?;
+        state.end()
+    }
+}
+
+/// Crow's-foot notation for one end of an edge, as seen from the side it is written on
+fn cardinality(field: &PrismaVizModelField, left: bool) -> &'static str {
+    match (field.arity.is_list(), field.arity.is_optional(), left) {
+        (true, _, true) => "}o",
+        (true, _, false) => "o{",
+        (false, true, true) => "|o",
+        (false, true, false) => "o|",
+        (false, false, _) => "||",
+    }
+}
+
+/// Finds the field on `target` that points back at `field` (declared on `model`)
+fn back_relation<'a>(
+    model: &PrismaVizModel,
+    field: &PrismaVizModelField,
+    target: &'a PrismaVizModel,
+) -> Option<&'a PrismaVizModelField> {
+    let relation_name = field.relation_name();
+    target.fields.iter().find(|f| {
+        f.r#type.get_data_type() == model.name
+            && f.relation_name() == relation_name
+            && !(model.name == target.name && f.name == field.name)
+    })
+}
+
+/// One relation line of an entity relationship diagram, in the crow's-foot notation Mermaid and
+/// PlantUML share
+pub(crate) struct Edge<'a> {
+    pub(crate) left: &'static str,
+    pub(crate) right: &'static str,
+    pub(crate) target: &'a str,
+    pub(crate) label: &'a str,
+}
+
+/// The relations `model` owns, written from its side
+pub(crate) fn edges<'a>(model: &'a PrismaVizModel, models: &[&'a PrismaVizModel]) -> Vec<Edge<'a>> {
+    let mut edges = vec![];
+    for field in model.fields.iter() {
+        let Some(target) = models
+            .iter()
+            .find(|m| m.name == field.r#type.get_data_type())
+        else {
+            continue;
+        };
+        let back = back_relation(model, field, target);
+        let is_owner = !field.relation_ships.fields().is_empty();
+        // Implicit many-to-many relations have no owning side, emit them once from the
+        // field that sorts first.
+        let is_implicit_many_to_many = match back {
+            Some(back) => {
+                field.arity.is_list()
+                    && back.arity.is_list()
+                    && (model.name.as_str(), field.name.as_str())
+                        < (target.name.as_str(), back.name.as_str())
+            }
+            None => false,
+        };
+        if !is_owner && !is_implicit_many_to_many {
+            continue;
+        }
+        edges.push(Edge {
+            left: back.map_or("}o", |b| cardinality(b, true)),
+            right: cardinality(field, false),
+            target: &target.name,
+            label: &field.name,
+        });
+    }
+    edges
+}
@@ -0,0 +1,222 @@
+//! Serde structs and enums mirroring the rows of the tables a schema describes
+use std::{collections::HashSet, fmt::Write};
+
+use psl::{
+    parser_database::{walkers::ModelWalker, ParserDatabase, ScalarFieldType, ScalarType},
+    schema_ast::ast::{FieldArity, WithDocumentation},
+};
+
+use crate::selection::Kept;
+
+/// Keywords and reserved words, which are written as raw identifiers
+const KEYWORDS: [&str; 47] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// Keywords that can not be raw identifiers either, which get a trailing `_` instead
+const PATH_KEYWORDS: [&str; 4] = ["crate", "self", "Self", "super"];
+
+fn split_words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    let chars: Vec<char> = name.chars().collect();
+    chars.iter().enumerate().for_each(|(index, c)| {
+        if !c.is_alphanumeric() {
+            words.push(String::new());
+            return;
+        }
+        let previous = index.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(index + 1);
+        // `userId` and `HTTPServer` start new words at `I` and `S`
+        let boundary = c.is_uppercase()
+            && previous.is_some_and(|p| {
+                p.is_lowercase()
+                    || p.is_numeric()
+                    || (p.is_uppercase() && next.is_some_and(|n| n.is_lowercase()))
+            });
+        match words.last_mut() {
+            Some(word) if !boundary => word.push(*c),
+            _ => words.push(c.to_string()),
+        }
+    });
+    words.into_iter().filter(|word| !word.is_empty()).collect()
+}
+
+/// `name` written so that it is not taken for a keyword
+fn escape(name: String) -> String {
+    match name.as_str() {
+        name if KEYWORDS.contains(&name) => format!("r#{}", name),
+        name if PATH_KEYWORDS.contains(&name) => format!("{}_", name),
+        _ => name,
+    }
+}
+
+/// `name`, numbered after `separator` when an earlier field or variant of the same declaration
+/// already took it
+fn deduplicate(name: String, separator: &str, taken: &mut HashSet<String>) -> String {
+    let mut unique = name.clone();
+    let mut number = 2;
+    while !taken.insert(unique.trim_start_matches("r#").to_string()) {
+        unique = format!("{}{}{}", name.trim_start_matches("r#"), separator, number);
+        number += 1;
+    }
+    unique
+}
+
+fn snake_case(name: &str) -> String {
+    let name = split_words(name)
+        .iter()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("_");
+    escape(name)
+}
+
+fn pascal_case(name: &str) -> String {
+    let name = split_words(name)
+        .iter()
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| {
+                    first
+                        .to_uppercase()
+                        .chain(chars.flat_map(char::to_lowercase))
+                        .collect()
+                })
+                .unwrap_or_default()
+        })
+        .collect::<Vec<String>>()
+        .join("");
+    escape(name)
+}
+
+fn render_documentation(out: &mut String, documentation: Option<&str>, indent: &str) {
+    if let Some(documentation) = documentation {
+        documentation
+            .lines()
+            .for_each(|line| writeln!(out, "{}/// {}", indent, line).unwrap());
+    }
+}
+
+fn scalar_type(scalar_type: ScalarType) -> &'static str {
+    match scalar_type {
+        ScalarType::Int => "i32",
+        ScalarType::BigInt => "i64",
+        ScalarType::Float => "f64",
+        ScalarType::Decimal => "rust_decimal::Decimal",
+        ScalarType::Boolean => "bool",
+        ScalarType::String => "String",
+        ScalarType::DateTime => "chrono::DateTime<chrono::Utc>",
+        ScalarType::Json => "serde_json::Value",
+        ScalarType::Bytes => "Vec<u8>",
+    }
+}
+
+fn field_type(db: &ParserDatabase, r#type: ScalarFieldType, arity: FieldArity) -> Option<String> {
+    let type_name = match r#type {
+        ScalarFieldType::BuiltInScalar(scalar) => scalar_type(scalar).to_string(),
+        ScalarFieldType::Enum(enum_id) => pascal_case(db.walk(enum_id).name()),
+        ScalarFieldType::CompositeType(composite_type_id) => {
+            pascal_case(db.walk(composite_type_id).name())
+        }
+        ScalarFieldType::Unsupported(_) => return None,
+    };
+    Some(match arity {
+        FieldArity::Required => type_name,
+        FieldArity::Optional => format!("Option<{}>", type_name),
+        FieldArity::List => format!("Vec<{}>", type_name),
+    })
+}
+
+/// One `pub` field, renamed to its column when the snake case name differs
+fn render_field(
+    out: &mut String,
+    taken: &mut HashSet<String>,
+    (name, database_name): (&str, &str),
+    type_name: &str,
+) {
+    let field_name = deduplicate(snake_case(name), "_", taken);
+    if field_name.trim_start_matches("r#") != database_name {
+        writeln!(out, "    #[serde(rename = \"{}\")]", database_name).unwrap();
+    }
+    writeln!(out, "    pub {}: {},", field_name, type_name).unwrap();
+}
+
+fn render_model(out: &mut String, model: ModelWalker<'_>) {
+    render_documentation(out, model.ast_model().documentation(), "");
+    out.push_str("#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]\n");
+    writeln!(out, "pub struct {} {{", pascal_case(model.name())).unwrap();
+    let mut taken = HashSet::new();
+    model
+        .scalar_fields()
+        .filter(|field| !field.is_ignored())
+        .for_each(|field| {
+            let Some(type_name) =
+                field_type(model.db, field.scalar_field_type(), field.ast_field().arity)
+            else {
+                return;
+            };
+            render_documentation(out, field.ast_field().documentation(), "    ");
+            let names = (field.name(), field.database_name());
+            render_field(out, &mut taken, names, &type_name);
+        });
+    out.push_str("}\n");
+}
+
+pub(crate) fn render(db: &ParserDatabase, kept: &Kept) -> String {
+    let mut declarations = vec![String::from("use serde::{Deserialize, Serialize};\n")];
+    db.walk_enums()
+        .filter(|r#enum| kept.r#enum(*r#enum))
+        .for_each(|r#enum| {
+            let mut out = String::new();
+            render_documentation(&mut out, r#enum.ast_enum().documentation(), "");
+            out.push_str("#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]\n");
+            writeln!(out, "pub enum {} {{", pascal_case(r#enum.name())).unwrap();
+            let mut taken = HashSet::new();
+            r#enum.values().for_each(|value| {
+                let variant = deduplicate(pascal_case(value.name()), "", &mut taken);
+                if variant != value.database_name() {
+                    writeln!(out, "    #[serde(rename = \"{}\")]", value.database_name()).unwrap();
+                }
+                writeln!(out, "    {},", variant).unwrap();
+            });
+            out.push_str("}\n");
+            declarations.push(out);
+        });
+    db.walk_composite_types()
+        .filter(|composite_type| kept.composite_type(*composite_type))
+        .for_each(|composite_type| {
+            let mut out = String::new();
+            render_documentation(
+                &mut out,
+                composite_type.ast_composite_type().documentation(),
+                "",
+            );
+            out.push_str("#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]\n");
+            writeln!(out, "pub struct {} {{", pascal_case(composite_type.name())).unwrap();
+            let mut taken = HashSet::new();
+            composite_type.fields().for_each(|field| {
+                if let Some(type_name) = field_type(db, field.r#type(), field.arity()) {
+                    render_documentation(&mut out, field.documentation(), "    ");
+                    let names = (field.name(), field.database_name());
+                    render_field(&mut out, &mut taken, names, &type_name);
+                }
+            });
+            out.push_str("}\n");
+            declarations.push(out);
+        });
+    db.walk_models()
+        .chain(db.walk_views())
+        .filter(|model| !model.is_ignored() && kept.model(*model))
+        .for_each(|model| {
+            let mut out = String::new();
+            render_model(&mut out, model);
+            declarations.push(out);
+        });
+    declarations.join("\n")
+}
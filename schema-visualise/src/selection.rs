@@ -0,0 +1,201 @@
+//! Focusing a schema on a subgraph, the models around some models or matching some names
+use std::collections::{BTreeSet, VecDeque};
+
+use psl::{
+    parser_database::{
+        walkers::{
+            CompositeTypeWalker, EnumWalker, ModelWalker, RelationFieldWalker, RelationWalker,
+        },
+        ParserDatabase, ScalarFieldType,
+    },
+    schema_ast::ast::{self, TopId},
+};
+
+/// Which models and views the renderers keep. Enums and composite types follow the models and
+/// views using them, relations are kept when both of their ends are
+#[derive(Clone, Debug)]
+pub struct Selection {
+    /// Models and views to walk the relations from, all of those `include` lets through when empty
+    pub models: Vec<String>,
+    /// How many relations away from `models` the walk goes
+    pub depth: usize,
+    /// Patterns the names of the models walked to must match one of, when there are any
+    pub include: Vec<String>,
+    /// Patterns the names of the models kept must not match
+    pub exclude: Vec<String>,
+}
+
+impl Default for Selection {
+    fn default() -> Selection {
+        Selection {
+            models: vec![],
+            depth: 1,
+            include: vec![],
+            exclude: vec![],
+        }
+    }
+}
+
+impl Selection {
+    /// Whether the selection keeps the whole schema
+    pub fn is_empty(&self) -> bool {
+        self.models.is_empty() && self.include.is_empty() && self.exclude.is_empty()
+    }
+    /// Names in `models` that are no model or view of `db`, the declarations it can start from
+    pub fn unknown_models(&self, db: &ParserDatabase) -> Vec<String> {
+        self.models
+            .iter()
+            .filter(|name| {
+                !db.walk_models()
+                    .chain(db.walk_views())
+                    .any(|model| model.name() == name.as_str())
+            })
+            .cloned()
+            .collect()
+    }
+    fn is_excluded(&self, name: &str) -> bool {
+        self.exclude.iter().any(|pattern| matches(pattern, name))
+    }
+    fn is_included(&self, name: &str) -> bool {
+        !self.is_excluded(name)
+            && (self.include.is_empty()
+                || self.include.iter().any(|pattern| matches(pattern, name)))
+    }
+}
+
+/// Glob match of `name` against `pattern`, `*` standing for any run of characters and `?` for one
+fn matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // Where the last `*` was seen in the pattern, and the name position it was tried against
+    let (mut p, mut n, mut star) = (0, 0, None);
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match star {
+                Some((star_p, star_n)) => {
+                    p = star_p + 1;
+                    n = star_n + 1;
+                    star = Some((star_p, star_n + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Models and views the selection keeps, walking the relations breadth first
+fn kept_models(db: &ParserDatabase, selection: &Selection) -> BTreeSet<ast::ModelId> {
+    let all = || db.walk_models().chain(db.walk_views());
+    if selection.models.is_empty() {
+        return all()
+            .filter(|model| selection.is_included(model.name()))
+            .map(|model| model.id)
+            .collect();
+    }
+    let mut kept = BTreeSet::new();
+    let mut queue: VecDeque<(ModelWalker<'_>, usize)> = all()
+        .filter(|model| selection.models.iter().any(|name| name == model.name()))
+        .filter(|model| !selection.is_excluded(model.name()))
+        .map(|model| (model, 0))
+        .collect();
+    queue.iter().for_each(|(model, _)| {
+        kept.insert(model.id);
+    });
+    while let Some((model, distance)) = queue.pop_front() {
+        if distance == selection.depth {
+            continue;
+        }
+        model
+            .relations_from()
+            .chain(model.relations_to())
+            .flat_map(|relation| relation.models())
+            .for_each(|id| {
+                let related = db.walk(id);
+                if selection.is_included(related.name()) && kept.insert(id) {
+                    queue.push_back((related, distance + 1));
+                }
+            });
+    }
+    kept
+}
+
+/// Enums and composite types `r#type` needs, composite types bringing in their own fields' types
+fn keep_type(db: &ParserDatabase, r#type: ScalarFieldType, kept: &mut BTreeSet<TopId>) {
+    match r#type {
+        ScalarFieldType::Enum(enum_id) => {
+            kept.insert(TopId::Enum(enum_id));
+        }
+        ScalarFieldType::CompositeType(composite_type_id) => {
+            if kept.insert(TopId::CompositeType(composite_type_id)) {
+                db.walk(composite_type_id)
+                    .fields()
+                    .for_each(|field| keep_type(db, field.r#type(), kept));
+            }
+        }
+        ScalarFieldType::BuiltInScalar(_) | ScalarFieldType::Unsupported(_) => {}
+    }
+}
+
+/// The models, views, enums and composite types a [`Selection`] keeps of a validated schema,
+/// which the renderers leave the others out by
+#[derive(Clone, Debug, Default)]
+pub struct Kept {
+    /// Everything is kept when `None`
+    tops: Option<BTreeSet<TopId>>,
+}
+
+impl Kept {
+    /// What `selection` keeps of `db`: the models it walks to, and the enums and composite types
+    /// they use
+    pub fn new(db: &ParserDatabase, selection: &Selection) -> Kept {
+        if selection.is_empty() {
+            return Kept::default();
+        }
+        let models = kept_models(db, selection);
+        let mut tops: BTreeSet<TopId> = models.iter().map(|id| TopId::Model(*id)).collect();
+        models.iter().for_each(|id| {
+            db.walk(*id)
+                .scalar_fields()
+                .for_each(|field| keep_type(db, field.scalar_field_type(), &mut tops));
+        });
+        Kept { tops: Some(tops) }
+    }
+    /// Whether `top` is kept, datasources and generators always are
+    pub fn contains(&self, top: TopId) -> bool {
+        match (&self.tops, top) {
+            (Some(tops), TopId::Model(_) | TopId::Enum(_) | TopId::CompositeType(_)) => {
+                tops.contains(&top)
+            }
+            _ => true,
+        }
+    }
+    pub(crate) fn model(&self, model: ModelWalker<'_>) -> bool {
+        self.contains(TopId::Model(model.id))
+    }
+    pub(crate) fn r#enum(&self, r#enum: EnumWalker<'_>) -> bool {
+        self.contains(TopId::Enum(r#enum.id))
+    }
+    pub(crate) fn composite_type(&self, composite_type: CompositeTypeWalker<'_>) -> bool {
+        self.contains(TopId::CompositeType(composite_type.id))
+    }
+    /// Whether both ends of `relation` are kept
+    pub(crate) fn relation(&self, relation: RelationWalker<'_>) -> bool {
+        relation
+            .models()
+            .iter()
+            .all(|id| self.contains(TopId::Model(*id)))
+    }
+    /// Whether the model `field` points at is kept, for fields of a kept model
+    pub(crate) fn relation_field(&self, field: RelationFieldWalker<'_>) -> bool {
+        self.model(field.related_model())
+    }
+}
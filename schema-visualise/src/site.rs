@@ -0,0 +1,39 @@
+//! Static inspector site, a bundle of files that also works when opened from `file://`
+use serde_json::{json, Map, Value};
+
+use crate::{highlight::Highlighter, SchemaVisualiser};
+
+const INDEX_HTML: &str = include_str!("site/index.html");
+const STYLE_CSS: &str = include_str!("site/style.css");
+const APP_JS: &str = include_str!("site/app.js");
+
+/// Highlighted HTML of every declaration, keyed by name, as `code_highlight` responds with it
+fn highlighted_code(visualiser: &SchemaVisualiser) -> Map<String, Value> {
+    let highlighter = Highlighter::new(&visualiser.schema);
+    visualiser
+        .models
+        .iter()
+        .map(|model| (model.name.clone(), highlighter.html_for(model.span).into()))
+        .collect()
+}
+
+/// Files of the site as `(path, contents)`, `visualiser` having been parsed
+pub(crate) fn render(visualiser: &SchemaVisualiser) -> Vec<(&'static str, String)> {
+    let visualise = serde_json::to_value(visualiser).expect("Failed to render JSON.");
+    let data = json!({
+        "visualise": visualise,
+        "code": highlighted_code(visualiser),
+    });
+    // Browsers refuse to fetch from `file://`, a script setting a global loads anyway
+    let data_js = format!("window.PRISMAVIZ = {};\n", data);
+    vec![
+        ("index.html", INDEX_HTML.to_string()),
+        ("style.css", STYLE_CSS.to_string()),
+        ("app.js", APP_JS.to_string()),
+        ("data.js", data_js),
+        (
+            "visualise.json",
+            serde_json::to_string_pretty(&visualise).expect("Failed to render JSON.") + "\n",
+        ),
+    ]
+}
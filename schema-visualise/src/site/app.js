@@ -0,0 +1,128 @@
+// Renders the inspector view out of `window.PRISMAVIZ`, which data.js sets to
+// `{ visualise, code }`: the `/api/v1/visualise` document and the highlighted
+// HTML of every declaration, keyed by name.
+(function () {
+  const { visualise, code } = window.PRISMAVIZ;
+  const element = (tag, properties, children) => {
+    const node = Object.assign(document.createElement(tag), properties);
+    (children || []).forEach((child) => node.append(child));
+    return node;
+  };
+  const kinds = {
+    model: "Models",
+    view: "Views",
+    enum: "Enums",
+    composite_type: "Types",
+  };
+  const names = new Set(visualise.result.map((model) => model.name));
+
+  const diagnostics = document.getElementById("diagnostics");
+  visualise.diagnostics.forEach((diagnostic) => {
+    diagnostics.append(
+      element("p", {
+        className: `diagnostic ${diagnostic.severity}`,
+        textContent: [diagnostic.file, diagnostic.severity, diagnostic.message]
+          .filter(Boolean)
+          .join(": "),
+      })
+    );
+  });
+
+  const nav = document.getElementById("models");
+  Object.entries(kinds).forEach(([kind, title]) => {
+    const models = visualise.result.filter((model) => model.kind === kind);
+    if (models.length === 0) return;
+    nav.append(element("div", { className: "kind", textContent: title }));
+    models.forEach((model) =>
+      nav.append(
+        element("a", { href: `#${model.name}`, textContent: model.name })
+      )
+    );
+  });
+
+  const typeCell = (field) => {
+    const type = field.type.replace(/[?[\]]/g, "");
+    const modifier = { optional: "?", list: "[]" }[field.arity] || "";
+    if (!names.has(type)) {
+      return element("td", { textContent: type + modifier });
+    }
+    return element("td", {}, [
+      element("a", { href: `#${type}`, textContent: type }),
+      modifier,
+    ]);
+  };
+
+  const table = (model) => {
+    const columns =
+      model.kind === "enum"
+        ? ["Value", "Database name"]
+        : ["Name", "Type", "Constraints", "Relation", "Database name"];
+    const rows = model.fields.map((field) =>
+      element(
+        "tr",
+        {},
+        model.kind === "enum"
+          ? [
+              element("td", { textContent: field.name }),
+              element("td", { textContent: field.database_name }),
+            ]
+          : [
+              element("td", { textContent: field.name }),
+              typeCell(field),
+              element("td", { textContent: field.constraints.join(", ") }),
+              element("td", {
+                textContent: [
+                  field.relation_ship_type,
+                  field.relation_ship_fields.join(", "),
+                  field.relation_ship_references.join(", "),
+                ]
+                  .filter(Boolean)
+                  .join(" "),
+              }),
+              element("td", { textContent: field.database_name }),
+            ]
+      )
+    );
+    return element("table", {}, [
+      element("thead", {}, [
+        element(
+          "tr",
+          {},
+          columns.map((column) => element("th", { textContent: column }))
+        ),
+      ]),
+      element("tbody", {}, rows),
+    ]);
+  };
+
+  const cards = document.getElementById("cards");
+  visualise.result.forEach((model) => {
+    const body = element("div", {}, [table(model)]);
+    let showCode = false;
+    const toggle = element("button", { textContent: "Code" });
+    toggle.addEventListener("click", () => {
+      showCode = !showCode;
+      toggle.textContent = showCode ? "Table" : "Code";
+      body.replaceChildren();
+      if (showCode) {
+        // Markup prismaviz generated, every piece of the source in it is escaped
+        body.innerHTML = code[model.name];
+      } else {
+        body.append(table(model));
+      }
+    });
+    cards.append(
+      element("article", { className: "card", id: model.name }, [
+        element("header", {}, [
+          element("h2", { textContent: model.name }),
+          element("span", {
+            className: "kind",
+            textContent: model.kind.replace("_", " "),
+          }),
+          toggle,
+        ]),
+        body,
+      ])
+    );
+  });
+})();
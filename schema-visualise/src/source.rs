@@ -0,0 +1,152 @@
+//! Schemas split over several `.prisma` files, parsed as one the way `prismaSchemaFolder` does
+use std::{io, path::Path};
+
+use psl_core::diagnostics::{DatamodelError, DatamodelWarning, Diagnostics, Span};
+use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+
+/// One file of a schema
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct SchemaFile {
+    /// Name spans and diagnostics in this file are reported under
+    pub name: String,
+    pub contents: String,
+}
+
+impl SchemaFile {
+    pub fn new(name: impl Into<String>, contents: impl Into<String>) -> SchemaFile {
+        SchemaFile {
+            name: name.into(),
+            contents: contents.into(),
+        }
+    }
+}
+
+/// Reads the files at `paths`, a directory standing for every `.prisma` file below it
+pub fn read(paths: &[String]) -> io::Result<Vec<SchemaFile>> {
+    let mut files = vec![];
+    paths
+        .iter()
+        .try_for_each(|path| read_into(Path::new(path), &mut files))?;
+    Ok(files)
+}
+
+fn read_into(path: &Path, files: &mut Vec<SchemaFile>) -> io::Result<()> {
+    let with_path =
+        |error: io::Error| io::Error::new(error.kind(), format!("{}: {}", path.display(), error));
+    if !path.is_dir() {
+        let contents = std::fs::read_to_string(path).map_err(with_path)?;
+        files.push(SchemaFile::new(path.display().to_string(), contents));
+        return Ok(());
+    }
+    let mut entries = std::fs::read_dir(path)
+        .and_then(|entries| {
+            entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<io::Result<Vec<_>>>()
+        })
+        .map_err(with_path)?;
+    // Sorted, so that the concatenated schema does not depend on the order of the directory
+    entries.sort();
+    entries
+        .iter()
+        .filter(|entry| {
+            entry.is_dir()
+                || entry
+                    .extension()
+                    .is_some_and(|extension| extension == "prisma")
+        })
+        .try_for_each(|entry| read_into(entry, files))
+}
+
+/// Where each file sits in the schema its files are concatenated into
+#[derive(Clone, Debug, Default)]
+pub struct SourceMap {
+    files: Vec<(String, Span)>,
+}
+
+impl SourceMap {
+    /// Concatenates `files` into one schema, putting a newline between them where it is missing
+    pub fn concat(files: &[SchemaFile]) -> (String, SourceMap) {
+        let mut schema = String::new();
+        let mut source_map = SourceMap::default();
+        files.iter().for_each(|file| {
+            if !schema.is_empty() && !schema.ends_with('\n') {
+                schema.push('\n');
+            }
+            let start = schema.len();
+            schema.push_str(&file.contents);
+            source_map
+                .files
+                .push((file.name.clone(), Span::new(start, schema.len())));
+        });
+        (schema, source_map)
+    }
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+    /// Names of the files, and their spans in the concatenated schema
+    pub fn files(&self) -> impl Iterator<Item = (&str, Span)> {
+        self.files.iter().map(|(name, span)| (name.as_str(), *span))
+    }
+    /// The file `span` starts in, and `span` relative to the start of that file
+    pub fn locate(&self, span: Span) -> Option<(&str, Span)> {
+        self.rebase(span)
+            .map(|(index, span)| (self.files[index].0.as_str(), span))
+    }
+    /// Name, contents and diagnostics of every file of `schema`, spans re-based onto the start of
+    /// the file
+    pub fn split<'a>(
+        &'a self,
+        schema: &'a str,
+        diagnostics: &Diagnostics,
+    ) -> Vec<(&'a str, &'a str, Diagnostics)> {
+        let mut per_file: Vec<(&str, &str, Diagnostics)> = self
+            .files()
+            .map(|(name, span)| (name, &schema[span.start..span.end], Diagnostics::new()))
+            .collect();
+        diagnostics.errors().iter().for_each(|error| {
+            if let Some((index, span)) = self.rebase(error.span()) {
+                per_file[index]
+                    .2
+                    .push_error(DatamodelError::new_legacy_parser_error(
+                        error.message().to_string(),
+                        span,
+                    ));
+            }
+        });
+        diagnostics.warnings().iter().for_each(|warning| {
+            if let Some((index, span)) = self.rebase(warning.span()) {
+                per_file[index]
+                    .2
+                    .push_warning(DatamodelWarning::new(warning.message().to_string(), span));
+            }
+        });
+        per_file
+    }
+    /// Index of the file `span` starts in, and `span` relative to the start of that file
+    pub(crate) fn rebase(&self, span: Span) -> Option<(usize, Span)> {
+        let index = self
+            .files
+            .iter()
+            .rposition(|(_, file)| file.start <= span.start)?;
+        let file = self.files[index].1;
+        let end = span.end.min(file.end).max(span.start);
+        Some((index, Span::new(span.start - file.start, end - file.start)))
+    }
+}
+
+impl Serialize for SourceMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        struct File<'a>(&'a str, Span);
+        impl Serialize for File<'_> {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let mut state = serializer.serialize_struct("File", 3)?;
+                state.serialize_field("name", self.0)?;
+                state.serialize_field("start", &self.1.start)?;
+                state.serialize_field("end", &self.1.end)?;
+                state.end()
+            }
+        }
+        serializer.collect_seq(self.files().map(|(name, span)| File(name, span)))
+    }
+}
@@ -0,0 +1,634 @@
+//! SQL DDL for a validated schema, close to what `prisma migrate` would create
+use std::fmt::Write;
+
+use psl::{
+    builtin_connectors::{COCKROACH, MSSQL, MYSQL, POSTGRES, SQLITE},
+    datamodel_connector::{walker_ext_traits::*, Connector, RelationMode},
+    parser_database::{
+        walkers::{
+            EnumWalker, ImplicitManyToManyRelationWalker, IndexWalker, InlineRelationWalker,
+            ModelWalker, ScalarFieldWalker,
+        },
+        IndexAlgorithm, ReferentialAction, ScalarFieldType, ScalarType, SortOrder,
+    },
+    schema_ast::ast::{self, Expression},
+    ValidatedSchema,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::selection::Kept;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Dialect {
+    Postgres,
+    Cockroachdb,
+    Mysql,
+    Sqlserver,
+    Sqlite,
+}
+
+impl Dialect {
+    /// The builtin connector whose native types and constraint names the dialect uses
+    pub fn connector(&self) -> &'static dyn Connector {
+        match self {
+            Dialect::Postgres => POSTGRES,
+            Dialect::Cockroachdb => COCKROACH,
+            Dialect::Mysql => MYSQL,
+            Dialect::Sqlserver => MSSQL,
+            Dialect::Sqlite => SQLITE,
+        }
+    }
+    /// The dialect of a builtin SQL connector, `None` for MongoDB
+    pub(crate) fn for_connector(connector: &dyn Connector) -> Option<Dialect> {
+        [
+            Dialect::Postgres,
+            Dialect::Cockroachdb,
+            Dialect::Mysql,
+            Dialect::Sqlserver,
+            Dialect::Sqlite,
+        ]
+        .into_iter()
+        .find(|dialect| dialect.connector().provider_name() == connector.provider_name())
+    }
+    pub fn quote(&self, identifier: &str) -> String {
+        match self {
+            Dialect::Mysql => format!("`{}`", identifier.replace('`', "``")),
+            Dialect::Sqlserver => format!("[{}]", identifier.replace(']', "]]")),
+            _ => format!("\"{}\"", identifier.replace('"', "\"\"")),
+        }
+    }
+    fn has_arrays(&self) -> bool {
+        matches!(self, Dialect::Postgres | Dialect::Cockroachdb)
+    }
+    fn has_enum_types(&self) -> bool {
+        matches!(self, Dialect::Postgres | Dialect::Cockroachdb)
+    }
+    /// Tables and types without `@@schema` end up here
+    fn default_schema(&self) -> Option<&'static str> {
+        match self {
+            Dialect::Sqlserver => Some("dbo"),
+            _ => None,
+        }
+    }
+}
+
+/// CREATE statements for the enums, tables, indexes and foreign keys of `schema`. Views are
+/// left out, and so are foreign keys when the relation mode is `prisma`.
+pub fn render(schema: &ValidatedSchema, kept: &Kept, dialect: Dialect) -> String {
+    let db = &schema.db;
+    let join_tables: Vec<ImplicitManyToManyRelationWalker<'_>> = db
+        .walk_relations()
+        .filter(|relation| !relation.is_ignored() && kept.relation(*relation))
+        .filter_map(|relation| relation.refine().as_many_to_many())
+        .collect();
+    let foreign_keys: Vec<InlineRelationWalker<'_>> = db
+        .walk_relations()
+        .filter(|relation| !relation.is_ignored() && kept.relation(*relation))
+        .filter_map(|relation| relation.refine().as_inline())
+        .filter(|relation| relation.as_complete().is_some())
+        .filter(|relation| {
+            !relation.referencing_model().ast_model().is_view()
+                && !relation.referenced_model().ast_model().is_view()
+        })
+        .filter(|_| schema.relation_mode().uses_foreign_keys())
+        .collect();
+
+    let mut statements = vec![];
+    if dialect.has_enum_types() {
+        db.walk_enums()
+            .filter(|r#enum| kept.r#enum(*r#enum))
+            .for_each(|r#enum| statements.push(create_enum(dialect, r#enum)));
+    }
+    db.walk_models()
+        .filter(|model| kept.model(*model))
+        .for_each(|model| {
+            let inline_keys: Vec<_> = foreign_keys
+                .iter()
+                .copied()
+                .filter(|relation| {
+                    dialect == Dialect::Sqlite && relation.referencing_model().id == model.id
+                })
+                .collect();
+            statements.push(create_table(
+                dialect,
+                model,
+                schema.relation_mode(),
+                &inline_keys,
+            ));
+        });
+    join_tables.iter().for_each(|relation| {
+        statements.push(create_join_table(
+            dialect,
+            schema.relation_mode(),
+            *relation,
+        ))
+    });
+    db.walk_models()
+        .filter(|model| kept.model(*model))
+        .flat_map(|model| model.indexes())
+        .for_each(|index| statements.push(create_index(dialect, index)));
+    join_tables.iter().for_each(|relation| {
+        let table = join_table_name(dialect, *relation);
+        let name = relation.table_name().to_string();
+        statements.push(format!(
+            "-- CreateIndex\nCREATE UNIQUE INDEX {} ON {}({}, {});\n",
+            dialect.quote(&format!("{}_AB_unique", name)),
+            table,
+            dialect.quote(relation.column_a_name()),
+            dialect.quote(relation.column_b_name())
+        ));
+        statements.push(format!(
+            "-- CreateIndex\nCREATE INDEX {} ON {}({});\n",
+            dialect.quote(&format!("{}_B_index", name)),
+            table,
+            dialect.quote(relation.column_b_name())
+        ));
+    });
+    if dialect != Dialect::Sqlite {
+        foreign_keys.iter().for_each(|relation| {
+            statements.push(format!(
+                "-- AddForeignKey\nALTER TABLE {} ADD {};\n",
+                table_name(dialect, relation.referencing_model()),
+                foreign_key(dialect, schema.relation_mode(), *relation)
+            ))
+        });
+        join_tables
+            .iter()
+            .filter(|_| schema.relation_mode().uses_foreign_keys())
+            .for_each(|relation| {
+                join_table_columns(*relation)
+                    .into_iter()
+                    .for_each(|(column, model)| {
+                        statements.push(format!(
+                            "-- AddForeignKey\nALTER TABLE {} ADD {};\n",
+                            join_table_name(dialect, *relation),
+                            join_table_foreign_key(dialect, *relation, column, model)
+                        ))
+                    })
+            });
+    }
+    statements.join("\n")
+}
+
+fn qualified_name(dialect: Dialect, schema: Option<&str>, name: &str) -> String {
+    match schema.or(dialect.default_schema()) {
+        Some(schema) => format!("{}.{}", dialect.quote(schema), dialect.quote(name)),
+        None => dialect.quote(name),
+    }
+}
+
+fn table_name(dialect: Dialect, model: ModelWalker<'_>) -> String {
+    qualified_name(dialect, model.schema_name(), model.database_name())
+}
+
+fn join_table_name(dialect: Dialect, relation: ImplicitManyToManyRelationWalker<'_>) -> String {
+    qualified_name(
+        dialect,
+        relation.model_a().schema_name(),
+        &relation.table_name().to_string(),
+    )
+}
+
+fn enum_name(dialect: Dialect, r#enum: EnumWalker<'_>) -> String {
+    qualified_name(
+        dialect,
+        r#enum.schema().map(|(schema, _)| schema),
+        r#enum.database_name(),
+    )
+}
+
+fn columns<'a>(dialect: Dialect, names: impl Iterator<Item = &'a str>) -> String {
+    names
+        .map(|name| dialect.quote(name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn create_enum(dialect: Dialect, r#enum: EnumWalker<'_>) -> String {
+    let values: Vec<String> = r#enum
+        .values()
+        .map(|value| quote_literal(value.database_name()))
+        .collect();
+    format!(
+        "-- CreateEnum\nCREATE TYPE {} AS ENUM ({});\n",
+        enum_name(dialect, r#enum),
+        values.join(", ")
+    )
+}
+
+/// `VarChar` and `["191"]` become `VARCHAR(191)`
+pub(crate) fn native_type_sql(name: &str, args: &[String]) -> String {
+    let args = match args.is_empty() {
+        true => String::new(),
+        false => format!("({})", args.join(",").to_uppercase()),
+    };
+    match name {
+        "DoublePrecision" => format!("DOUBLE PRECISION{}", args),
+        "CatalogSingleChar" => format!("\"char\"{}", args),
+        _ => match name.strip_prefix("Unsigned") {
+            Some(signed) => format!("{}{} UNSIGNED", signed.to_uppercase(), args),
+            None => format!("{}{}", name.to_uppercase(), args),
+        },
+    }
+}
+
+pub(crate) fn scalar_type_sql(dialect: Dialect, scalar_type: ScalarType) -> String {
+    match dialect {
+        Dialect::Sqlite => match scalar_type {
+            ScalarType::Int => "INTEGER",
+            ScalarType::BigInt => "BIGINT",
+            ScalarType::Float => "REAL",
+            ScalarType::Decimal => "DECIMAL",
+            ScalarType::Boolean => "BOOLEAN",
+            ScalarType::String | ScalarType::Json => "TEXT",
+            ScalarType::DateTime => "DATETIME",
+            ScalarType::Bytes => "BLOB",
+        }
+        .to_string(),
+        _ => {
+            let connector = dialect.connector();
+            let native_type = connector.default_native_type_for_scalar_type(&scalar_type);
+            let (name, args) = connector.native_type_to_parts(&native_type);
+            native_type_sql(name, &args)
+        }
+    }
+}
+
+/// The column type of `field`, from its `@db` attribute when the dialect understands it
+fn column_type(dialect: Dialect, field: ScalarFieldWalker<'_>) -> String {
+    let connector = dialect.connector();
+    let base = match field.scalar_field_type() {
+        ScalarFieldType::BuiltInScalar(scalar_type) => {
+            match field.native_type_instance(connector) {
+                Some(native_type) => {
+                    let (name, args) = connector.native_type_to_parts(&native_type);
+                    native_type_sql(name, &args)
+                }
+                None => scalar_type_sql(dialect, scalar_type),
+            }
+        }
+        ScalarFieldType::Enum(enum_id) => {
+            let r#enum = field.db.walk(enum_id);
+            match dialect {
+                Dialect::Postgres | Dialect::Cockroachdb => enum_name(dialect, r#enum),
+                Dialect::Mysql => {
+                    let values: Vec<String> = r#enum
+                        .values()
+                        .map(|value| quote_literal(value.database_name()))
+                        .collect();
+                    format!("ENUM({})", values.join(", "))
+                }
+                _ => scalar_type_sql(dialect, ScalarType::String),
+            }
+        }
+        ScalarFieldType::CompositeType(_) => scalar_type_sql(dialect, ScalarType::Json),
+        ScalarFieldType::Unsupported(_) => match &field.ast_field().field_type {
+            ast::FieldType::Unsupported(name, _) => name.clone(),
+            ast::FieldType::Supported(identifier) => identifier.name.clone(),
+        },
+    };
+    match field.is_list() {
+        true if dialect.has_arrays() => format!("{}[]", base),
+        true => scalar_type_sql(dialect, ScalarType::Json),
+        false => base,
+    }
+}
+
+fn literal(dialect: Dialect, field: ScalarFieldWalker<'_>, value: &Expression) -> Option<String> {
+    match value {
+        Expression::StringValue(value, _) => Some(quote_literal(value)),
+        Expression::NumericValue(value, _) => Some(value.clone()),
+        Expression::ConstantValue(value, _) => match field.field_type_as_enum() {
+            Some(r#enum) => r#enum
+                .values()
+                .find(|enum_value| enum_value.name() == value)
+                .map(|enum_value| quote_literal(enum_value.database_name())),
+            None if dialect == Dialect::Sqlserver => match value.as_str() {
+                "true" => Some("1".to_string()),
+                _ => Some("0".to_string()),
+            },
+            None => Some(value.clone()),
+        },
+        _ => None,
+    }
+}
+
+/// The `DEFAULT` expression of `field`, for the defaults the database evaluates
+fn default_sql(dialect: Dialect, field: ScalarFieldWalker<'_>) -> Option<String> {
+    let default = field.default_value()?;
+    match default.value() {
+        Expression::Function(name, _, _) if name == "now" => {
+            let precision = field
+                .native_type_instance(dialect.connector())
+                .map(|native_type| dialect.connector().native_type_to_parts(&native_type).1)
+                .filter(|args| dialect == Dialect::Mysql && !args.is_empty());
+            match precision {
+                Some(args) => Some(format!("CURRENT_TIMESTAMP({})", args.join(","))),
+                None => Some("CURRENT_TIMESTAMP".to_string()),
+            }
+        }
+        Expression::Function(name, arguments, _) if name == "dbgenerated" => arguments
+            .arguments
+            .first()
+            .and_then(|argument| argument.value.as_string_value())
+            .map(|(value, _)| value.to_string())
+            .filter(|value| !value.is_empty()),
+        Expression::Function(_, _, _) => None,
+        Expression::Array(values, _) if dialect.has_arrays() => {
+            let values: Option<Vec<String>> = values
+                .iter()
+                .map(|value| literal(dialect, field, value))
+                .collect();
+            values.map(|values| format!("ARRAY[{}]", values.join(", ")))
+        }
+        Expression::Array(_, _) => None,
+        value => literal(dialect, field, value),
+    }
+}
+
+fn column(dialect: Dialect, field: ScalarFieldWalker<'_>) -> String {
+    let mut column_type = column_type(dialect, field);
+    let autoincrement = field.is_autoincrement();
+    if autoincrement && dialect == Dialect::Postgres {
+        column_type = match column_type.as_str() {
+            "SMALLINT" => "SMALLSERIAL".to_string(),
+            "BIGINT" => "BIGSERIAL".to_string(),
+            _ => "SERIAL".to_string(),
+        };
+    }
+    let mut out = format!(
+        "    {} {}",
+        dialect.quote(field.database_name()),
+        column_type
+    );
+    if !field.is_optional() && !field.is_list() {
+        out.push_str(" NOT NULL");
+    }
+    match dialect {
+        Dialect::Cockroachdb if autoincrement => out.push_str(" DEFAULT unique_rowid()"),
+        Dialect::Mysql if autoincrement => out.push_str(" AUTO_INCREMENT"),
+        Dialect::Sqlserver if autoincrement => out.push_str(" IDENTITY(1,1)"),
+        Dialect::Sqlite if autoincrement => out.push_str(" PRIMARY KEY AUTOINCREMENT"),
+        Dialect::Sqlserver => {
+            if let Some(value) = default_sql(dialect, field) {
+                let name = field
+                    .default_value()
+                    .unwrap()
+                    .constraint_name(dialect.connector());
+                write!(
+                    out,
+                    " CONSTRAINT {} DEFAULT {}",
+                    dialect.quote(&name),
+                    value
+                )
+                .unwrap();
+            }
+        }
+        _ => {
+            if let Some(value) = default_sql(dialect, field) {
+                write!(out, " DEFAULT {}", value).unwrap();
+            }
+        }
+    }
+    out
+}
+
+fn referential_action(action: ReferentialAction) -> &'static str {
+    match action {
+        ReferentialAction::Cascade => "CASCADE",
+        ReferentialAction::Restrict => "RESTRICT",
+        ReferentialAction::NoAction => "NO ACTION",
+        ReferentialAction::SetNull => "SET NULL",
+        ReferentialAction::SetDefault => "SET DEFAULT",
+    }
+}
+
+fn foreign_key(
+    dialect: Dialect,
+    relation_mode: RelationMode,
+    relation: InlineRelationWalker<'_>,
+) -> String {
+    let connector = dialect.connector();
+    let complete = relation.as_complete().unwrap();
+    format!(
+        "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}({}) ON DELETE {} ON UPDATE {}",
+        dialect.quote(&relation.constraint_name(connector)),
+        columns(
+            dialect,
+            complete
+                .referencing_fields()
+                .map(|field| field.database_name())
+        ),
+        table_name(dialect, complete.referenced_model()),
+        columns(
+            dialect,
+            complete
+                .referenced_fields()
+                .map(|field| field.database_name())
+        ),
+        referential_action(complete.on_delete(connector, relation_mode)),
+        referential_action(complete.on_update())
+    )
+}
+
+fn create_table(
+    dialect: Dialect,
+    model: ModelWalker<'_>,
+    relation_mode: RelationMode,
+    inline_keys: &[InlineRelationWalker<'_>],
+) -> String {
+    let connector = dialect.connector();
+    let columns_sql: Vec<String> = model
+        .scalar_fields()
+        .map(|field| column(dialect, field))
+        .collect();
+    let mut constraints = vec![];
+    // SQLite declares an autoincrementing primary key on the column itself
+    let inline_primary_key = dialect == Dialect::Sqlite
+        && model
+            .scalar_fields()
+            .any(|field| field.is_autoincrement() && field.is_single_pk());
+    if let Some(primary_key) = model.primary_key().filter(|_| !inline_primary_key) {
+        let mut constraint = String::new();
+        if let Some(name) = primary_key.constraint_name(connector) {
+            write!(constraint, "CONSTRAINT {} ", dialect.quote(&name)).unwrap();
+        }
+        constraint.push_str("PRIMARY KEY ");
+        if dialect == Dialect::Sqlserver {
+            match primary_key.clustered() {
+                Some(false) => constraint.push_str("NONCLUSTERED "),
+                _ => constraint.push_str("CLUSTERED "),
+            }
+        }
+        write!(
+            constraint,
+            "({})",
+            columns(
+                dialect,
+                primary_key.fields().map(|field| field.database_name())
+            )
+        )
+        .unwrap();
+        constraints.push(format!("    {}", constraint));
+    }
+    // SQLite can not add foreign keys to an existing table, so they go into CREATE TABLE
+    inline_keys.iter().for_each(|relation| {
+        constraints.push(format!(
+            "    {}",
+            foreign_key(dialect, relation_mode, *relation)
+        ))
+    });
+
+    let mut out = format!(
+        "-- CreateTable\nCREATE TABLE {} (\n",
+        table_name(dialect, model)
+    );
+    out.push_str(&columns_sql.join(",\n"));
+    if !constraints.is_empty() {
+        out.push_str(",\n\n");
+        out.push_str(&constraints.join(",\n"));
+    }
+    out.push('\n');
+    out.push_str(table_end(dialect));
+    out
+}
+
+fn table_end(dialect: Dialect) -> &'static str {
+    match dialect {
+        Dialect::Mysql => ") DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;\n",
+        _ => ");\n",
+    }
+}
+
+/// Type of the single field primary key that a join table column points at
+fn key_type(dialect: Dialect, model: ModelWalker<'_>) -> String {
+    model
+        .primary_key()
+        .and_then(|primary_key| primary_key.fields().next())
+        .map(|field| column_type(dialect, field))
+        .unwrap_or_else(|| scalar_type_sql(dialect, ScalarType::Int))
+}
+
+fn create_join_table(
+    dialect: Dialect,
+    relation_mode: RelationMode,
+    relation: ImplicitManyToManyRelationWalker<'_>,
+) -> String {
+    let mut out = format!(
+        "-- CreateTable\nCREATE TABLE {} (\n",
+        join_table_name(dialect, relation)
+    );
+    let columns_sql: Vec<String> = join_table_columns(relation)
+        .into_iter()
+        .map(|(column, model)| {
+            format!(
+                "    {} {} NOT NULL",
+                dialect.quote(column),
+                key_type(dialect, model)
+            )
+        })
+        .collect();
+    out.push_str(&columns_sql.join(",\n"));
+    if dialect == Dialect::Sqlite && relation_mode.uses_foreign_keys() {
+        let constraints: Vec<String> = join_table_columns(relation)
+            .into_iter()
+            .map(|(column, model)| {
+                format!(
+                    "    {}",
+                    join_table_foreign_key(dialect, relation, column, model)
+                )
+            })
+            .collect();
+        out.push_str(",\n\n");
+        out.push_str(&constraints.join(",\n"));
+    }
+    out.push('\n');
+    out.push_str(table_end(dialect));
+    out
+}
+
+fn join_table_columns(
+    relation: ImplicitManyToManyRelationWalker<'_>,
+) -> [(&'static str, ModelWalker<'_>); 2] {
+    [
+        (relation.column_a_name(), relation.model_a()),
+        (relation.column_b_name(), relation.model_b()),
+    ]
+}
+
+fn join_table_foreign_key(
+    dialect: Dialect,
+    relation: ImplicitManyToManyRelationWalker<'_>,
+    column: &str,
+    model: ModelWalker<'_>,
+) -> String {
+    let key: Vec<&str> = model
+        .primary_key()
+        .map(|primary_key| {
+            primary_key
+                .fields()
+                .map(|field| field.database_name())
+                .collect()
+        })
+        .unwrap_or_default();
+    format!(
+        "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}({}) ON DELETE CASCADE ON UPDATE CASCADE",
+        dialect.quote(&format!("{}_{}_fkey", relation.table_name(), column)),
+        dialect.quote(column),
+        table_name(dialect, model),
+        columns(dialect, key.into_iter())
+    )
+}
+
+fn create_index(dialect: Dialect, index: IndexWalker<'_>) -> String {
+    let mut out = String::from("-- CreateIndex\nCREATE ");
+    if index.is_unique() {
+        out.push_str("UNIQUE ");
+    }
+    if index.is_fulltext() && dialect == Dialect::Mysql {
+        out.push_str("FULLTEXT ");
+    }
+    if dialect == Dialect::Sqlserver {
+        match index.clustered() {
+            Some(true) => out.push_str("CLUSTERED "),
+            _ => out.push_str("NONCLUSTERED "),
+        }
+    }
+    write!(
+        out,
+        "INDEX {} ON {}",
+        dialect.quote(&index.constraint_name(dialect.connector())),
+        table_name(dialect, index.model())
+    )
+    .unwrap();
+    match index.algorithm() {
+        Some(IndexAlgorithm::BTree) | None => (),
+        Some(algorithm) if dialect.has_arrays() => {
+            write!(out, " USING {}", algorithm.to_string().to_uppercase()).unwrap()
+        }
+        Some(_) => (),
+    }
+    let index_columns: Vec<String> = index
+        .scalar_field_attributes()
+        .map(|attribute| {
+            let path = attribute.as_mapped_path_to_indexed_field();
+            let mut column = dialect.quote(path.first().map(|(name, _)| *name).unwrap_or_default());
+            if let Some(length) = attribute.length().filter(|_| dialect == Dialect::Mysql) {
+                write!(column, "({})", length).unwrap();
+            }
+            match attribute.sort_order() {
+                Some(SortOrder::Asc) => column.push_str(" ASC"),
+                Some(SortOrder::Desc) => column.push_str(" DESC"),
+                None => (),
+            }
+            column
+        })
+        .collect();
+    writeln!(out, "({});", index_columns.join(", ")).unwrap();
+    out
+}
@@ -0,0 +1,453 @@
+//! Self contained SVG entity relationship diagram, laid out without Graphviz
+use std::fmt::Write;
+
+use crate::{
+    relations::{self, Edge},
+    PrismaVizModel, PrismaVizModelKind,
+};
+
+/// Advance of one character of the monospace font at `FONT_SIZE`
+const CHAR_WIDTH: i32 = 8;
+const FONT_SIZE: i32 = 13;
+const HEADER_HEIGHT: i32 = 28;
+const ROW_HEIGHT: i32 = 22;
+const PADDING: i32 = 10;
+const COLUMN_GAP: i32 = 16;
+/// Least horizontal space between two layers, the relations are routed through it
+const LAYER_GAP: i32 = 120;
+const NODE_GAP: i32 = 40;
+const MARGIN: i32 = 20;
+const LANE_SPACING: i32 = 10;
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn text_width(value: &str) -> i32 {
+    value.chars().count() as i32 * CHAR_WIDTH
+}
+
+struct Row {
+    name: String,
+    r#type: String,
+    keys: String,
+}
+
+struct Node<'a> {
+    model: &'a PrismaVizModel,
+    rows: Vec<Row>,
+    /// Widths of the name, type and key columns
+    columns: [i32; 3],
+    width: i32,
+    height: i32,
+    layer: usize,
+    x: i32,
+    y: i32,
+}
+
+impl<'a> Node<'a> {
+    fn new(model: &'a PrismaVizModel, models: &[&PrismaVizModel]) -> Node<'a> {
+        let rows: Vec<Row> = model
+            .fields
+            .iter()
+            .filter(|f| !models.iter().any(|m| m.name == f.r#type.get_data_type()))
+            .map(|field| Row {
+                name: field.name.clone(),
+                r#type: field.r#type.resolve_with_modifier(),
+                keys: model.key_markers(field).join(", "),
+            })
+            .collect();
+        let column = |value: fn(&Row) -> &str| rows.iter().map(|row| text_width(value(row))).max();
+        let columns = [
+            column(|row| &row.name).unwrap_or_default(),
+            column(|row| &row.r#type).unwrap_or_default(),
+            column(|row| &row.keys).unwrap_or_default(),
+        ];
+        let rows_width = columns.iter().sum::<i32>() + 2 * COLUMN_GAP;
+        let width = rows_width.max(text_width(&model.name)) + 2 * PADDING;
+        Node {
+            height: HEADER_HEIGHT + rows.len() as i32 * ROW_HEIGHT,
+            model,
+            rows,
+            columns,
+            width,
+            layer: 0,
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// Vertical centre of the row of `field`, or of the header when it has no row
+    fn anchor(&self, field: Option<&str>) -> i32 {
+        match field.and_then(|name| self.rows.iter().position(|row| row.name == name)) {
+            Some(index) => self.y + HEADER_HEIGHT + index as i32 * ROW_HEIGHT + ROW_HEIGHT / 2,
+            None => self.y + HEADER_HEIGHT / 2,
+        }
+    }
+}
+
+struct Link<'a> {
+    source: usize,
+    target: usize,
+    edge: Edge<'a>,
+    source_field: Option<String>,
+    target_field: Option<String>,
+}
+
+/// Depth first search marking the links that close a cycle, they are left out of layering
+fn back_links(nodes: usize, links: &[Link<'_>]) -> Vec<bool> {
+    fn visit(node: usize, links: &[Link<'_>], state: &mut [u8], back: &mut [bool]) {
+        state[node] = 1;
+        links
+            .iter()
+            .enumerate()
+            .filter(|(_, link)| link.source == node)
+            .for_each(|(index, link)| match state[link.target] {
+                0 => visit(link.target, links, state, back),
+                1 => back[index] = true,
+                _ => (),
+            });
+        state[node] = 2;
+    }
+    let mut state = vec![0; nodes];
+    let mut back = vec![false; links.len()];
+    (0..nodes).for_each(|node| {
+        if state[node] == 0 {
+            visit(node, links, &mut state, &mut back)
+        }
+    });
+    back
+}
+
+/// Longest path layering, every referenced model ends up right of the models referencing it
+fn assign_layers(nodes: &mut [Node<'_>], links: &[Link<'_>]) {
+    let back = back_links(nodes.len(), links);
+    let mut changed = true;
+    while changed {
+        changed = false;
+        links
+            .iter()
+            .zip(back.iter())
+            .filter(|(link, back)| !**back && link.source != link.target)
+            .for_each(|(link, _)| {
+                if nodes[link.target].layer <= nodes[link.source].layer {
+                    nodes[link.target].layer = nodes[link.source].layer + 1;
+                    changed = true;
+                }
+            });
+    }
+}
+
+/// Orders every layer by the mean position of the neighbours, sweeping back and forth
+fn order_layers(nodes: &[Node<'_>], links: &[Link<'_>]) -> Vec<Vec<usize>> {
+    let layer_count = nodes
+        .iter()
+        .map(|node| node.layer + 1)
+        .max()
+        .unwrap_or_default();
+    let mut layers: Vec<Vec<usize>> = vec![vec![]; layer_count];
+    nodes
+        .iter()
+        .enumerate()
+        .for_each(|(index, node)| layers[node.layer].push(index));
+    let neighbours = |node: usize| {
+        links.iter().filter_map(move |link| match link {
+            link if link.source == node && link.target != node => Some(link.target),
+            link if link.target == node && link.source != node => Some(link.source),
+            _ => None,
+        })
+    };
+    for sweep in 0..4 {
+        let mut position = vec![0.0; nodes.len()];
+        layers.iter().for_each(|layer| {
+            layer
+                .iter()
+                .enumerate()
+                .for_each(|(index, node)| position[*node] = index as f64)
+        });
+        let order: Vec<usize> = match sweep % 2 {
+            0 => (0..layer_count).collect(),
+            _ => (0..layer_count).rev().collect(),
+        };
+        for layer in order {
+            let barycenter = |node: usize| {
+                let positions: Vec<f64> = neighbours(node)
+                    .filter(|neighbour| nodes[*neighbour].layer != layer)
+                    .map(|neighbour| position[neighbour])
+                    .collect();
+                match positions.is_empty() {
+                    true => position[node],
+                    false => positions.iter().sum::<f64>() / positions.len() as f64,
+                }
+            };
+            let mut keyed: Vec<(f64, usize)> = layers[layer]
+                .iter()
+                .map(|node| (barycenter(*node), *node))
+                .collect();
+            keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+            layers[layer] = keyed.into_iter().map(|(_, node)| node).collect();
+            layers[layer]
+                .iter()
+                .enumerate()
+                .for_each(|(index, node)| position[*node] = index as f64);
+        }
+    }
+    layers
+}
+
+/// Places the layers as columns, each one centred vertically and followed by the gap of
+/// `gaps`. Returns the right edge of every column and the size of the drawing, which keeps a
+/// gap right of the last column for routing.
+fn place(nodes: &mut [Node<'_>], layers: &[Vec<usize>], gaps: &[i32]) -> (Vec<i32>, i32, i32) {
+    let column_heights: Vec<i32> = layers
+        .iter()
+        .map(|layer| {
+            layer.iter().map(|node| nodes[*node].height).sum::<i32>()
+                + NODE_GAP * (layer.len() as i32 - 1).max(0)
+        })
+        .collect();
+    let height = column_heights.iter().copied().max().unwrap_or_default();
+    let mut x = MARGIN;
+    let mut right_edges = vec![];
+    for ((layer, column_height), gap) in layers.iter().zip(column_heights).zip(gaps) {
+        let column_width = layer
+            .iter()
+            .map(|node| nodes[*node].width)
+            .max()
+            .unwrap_or_default();
+        let mut y = MARGIN + (height - column_height) / 2;
+        layer.iter().for_each(|node| {
+            nodes[*node].x = x;
+            nodes[*node].y = y;
+            y += nodes[*node].height + NODE_GAP;
+        });
+        right_edges.push(x + column_width);
+        x += column_width + gap;
+    }
+    (right_edges, x + MARGIN, height + 2 * MARGIN)
+}
+
+/// `1`, `0..1` or `*` for one end of a crow's-foot edge
+fn multiplicity(cardinality: &str) -> &'static str {
+    match cardinality {
+        "||" => "1",
+        "|o" | "o|" => "0..1",
+        _ => "*",
+    }
+}
+
+fn render_multiplicity(out: &mut String, x: i32, y: i32, towards_right: bool, cardinality: &str) {
+    let anchor = match towards_right {
+        true => "start",
+        false => "end",
+    };
+    let x = match towards_right {
+        true => x + 4,
+        false => x - 4,
+    };
+    writeln!(
+        out,
+        r#"    <text class="multiplicity" x="{}" y="{}" text-anchor="{}">{}</text>"#,
+        x,
+        y - 4,
+        anchor,
+        multiplicity(cardinality)
+    )
+    .unwrap();
+}
+
+/// Routes one relation as horizontal and vertical segments through the lane `lane_x`
+fn render_link(out: &mut String, nodes: &[Node<'_>], link: &Link<'_>, lane_x: i32) {
+    let (source, target) = (&nodes[link.source], &nodes[link.target]);
+    let source_y = source.anchor(link.source_field.as_deref());
+    let target_y = target.anchor(link.target_field.as_deref());
+    let (source_x, target_x) = match source.layer.cmp(&target.layer) {
+        std::cmp::Ordering::Less => (source.x + source.width, target.x),
+        std::cmp::Ordering::Greater => (source.x, target.x + target.width),
+        std::cmp::Ordering::Equal => (source.x + source.width, target.x + target.width),
+    };
+    writeln!(
+        out,
+        r#"  <g class="relation"><title>{}.{}</title>"#,
+        escape(&source.model.name),
+        escape(link.edge.label)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        r#"    <path d="M {} {} H {} V {} H {}"/>"#,
+        source_x, source_y, lane_x, target_y, target_x
+    )
+    .unwrap();
+    render_multiplicity(out, source_x, source_y, lane_x > source_x, link.edge.left);
+    render_multiplicity(out, target_x, target_y, lane_x > target_x, link.edge.right);
+    writeln!(
+        out,
+        r#"    <text class="label" x="{}" y="{}">{}</text>"#,
+        lane_x + 4,
+        (source_y + target_y) / 2,
+        escape(link.edge.label)
+    )
+    .unwrap();
+    out.push_str("  </g>\n");
+}
+
+fn render_node(out: &mut String, node: &Node<'_>) {
+    let class = match node.model.kind {
+        PrismaVizModelKind::View => "model view",
+        _ => "model",
+    };
+    writeln!(
+        out,
+        r#"  <g class="{}" id="model-{}">"#,
+        class,
+        escape(&node.model.name)
+    )
+    .unwrap();
+    writeln!(
+        out,
+        r#"    <rect class="box" x="{}" y="{}" width="{}" height="{}" rx="4"/>"#,
+        node.x, node.y, node.width, node.height
+    )
+    .unwrap();
+    writeln!(
+        out,
+        r#"    <rect class="header" x="{}" y="{}" width="{}" height="{}" rx="4"/>"#,
+        node.x, node.y, node.width, HEADER_HEIGHT
+    )
+    .unwrap();
+    writeln!(
+        out,
+        r#"    <text class="name" x="{}" y="{}" text-anchor="middle">{}</text>"#,
+        node.x + node.width / 2,
+        node.y + HEADER_HEIGHT / 2 + FONT_SIZE / 3,
+        escape(&node.model.name)
+    )
+    .unwrap();
+    node.rows.iter().enumerate().for_each(|(index, row)| {
+        let top = node.y + HEADER_HEIGHT + index as i32 * ROW_HEIGHT;
+        let baseline = top + ROW_HEIGHT / 2 + FONT_SIZE / 3;
+        if index > 0 {
+            writeln!(
+                out,
+                r#"    <line class="separator" x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+                node.x,
+                top,
+                node.x + node.width,
+                top
+            )
+            .unwrap();
+        }
+        let mut x = node.x + PADDING;
+        [&row.name, &row.r#type, &row.keys]
+            .iter()
+            .zip(["field", "type", "keys"])
+            .zip(node.columns)
+            .for_each(|((value, class), width)| {
+                if !value.is_empty() {
+                    writeln!(
+                        out,
+                        r#"    <text class="{}" x="{}" y="{}">{}</text>"#,
+                        class,
+                        x,
+                        baseline,
+                        escape(value)
+                    )
+                    .unwrap();
+                }
+                x += width + COLUMN_GAP;
+            });
+    });
+    out.push_str("  </g>\n");
+}
+
+pub(crate) fn render(models: &[PrismaVizModel]) -> String {
+    let models = models
+        .iter()
+        .filter(|m| m.kind.is_model_like())
+        .collect::<Vec<_>>();
+    let mut nodes: Vec<Node<'_>> = models
+        .iter()
+        .map(|model| Node::new(model, &models))
+        .collect();
+    let links: Vec<Link<'_>> = models
+        .iter()
+        .enumerate()
+        .flat_map(|(source, model)| {
+            let models = &models;
+            relations::edges(model, models)
+                .into_iter()
+                .map(move |edge| {
+                    let field = model.fields.iter().find(|field| field.name == edge.label);
+                    let relation_ships = field.map(|field| &field.relation_ships);
+                    Link {
+                        source,
+                        target: models.iter().position(|m| m.name == edge.target).unwrap(),
+                        source_field: relation_ships.and_then(|r| r.fields().into_iter().next()),
+                        target_field: relation_ships
+                            .and_then(|r| r.references().into_iter().next()),
+                        edge,
+                    }
+                })
+        })
+        .collect();
+    assign_layers(&mut nodes, &links);
+    let layers = order_layers(&nodes, &links);
+
+    // Every relation gets its own vertical lane in the gap right of the column it leaves,
+    // or left of it when it points back at an earlier layer. Gaps widen to fit their lanes.
+    let mut lanes_used = vec![0; layers.len()];
+    let lanes: Vec<(usize, i32)> = links
+        .iter()
+        .map(|link| {
+            let (source, target) = (&nodes[link.source], &nodes[link.target]);
+            let gap = match source.layer > target.layer {
+                true => source.layer - 1,
+                false => source.layer,
+            };
+            lanes_used[gap] += 1;
+            (gap, lanes_used[gap] - 1)
+        })
+        .collect();
+    let gaps: Vec<i32> = lanes_used
+        .iter()
+        .map(|lanes| LAYER_GAP.max(2 * COLUMN_GAP + (lanes - 1).max(0) * LANE_SPACING))
+        .collect();
+    let (right_edges, width, height) = place(&mut nodes, &layers, &gaps);
+    let lanes = lanes
+        .into_iter()
+        .map(|(gap, lane)| right_edges[gap] + COLUMN_GAP + lane * LANE_SPACING);
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}" font-family="monospace" font-size="{}">"#,
+        width, height, width, height, FONT_SIZE
+    )
+    .unwrap();
+    out.push_str(
+        r#"  <style>
+    .box { fill: #ffffff; stroke: #4b5563; }
+    .header { fill: #e5e7eb; stroke: #4b5563; }
+    .view .header { fill: #dbeafe; }
+    .name { font-weight: bold; }
+    .separator { stroke: #e5e7eb; }
+    .type { fill: #2563eb; }
+    .keys { fill: #b45309; }
+    .relation path { fill: none; stroke: #6b7280; }
+    .multiplicity, .label { fill: #6b7280; font-size: 11px; }
+  </style>
+"#,
+    );
+    links
+        .iter()
+        .zip(lanes)
+        .for_each(|(link, lane_x)| render_link(&mut out, &nodes, link, lane_x));
+    nodes.iter().for_each(|node| render_node(&mut out, node));
+    out.push_str("</svg>\n");
+    out
+}
@@ -0,0 +1,184 @@
+//! TypeScript declarations for the models, views, enums and composite types of a schema
+use std::fmt::Write;
+
+use psl::{
+    parser_database::{
+        walkers::{ModelWalker, RefinedFieldWalker},
+        ParserDatabase, ScalarFieldType, ScalarType,
+    },
+    schema_ast::ast::{FieldArity, WithDocumentation},
+};
+
+use crate::selection::Kept;
+
+/// TypeScript types for the scalars that have no single natural counterpart
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypescriptOptions {
+    pub decimal: String,
+    pub bigint: String,
+    pub json: String,
+    pub bytes: String,
+}
+
+impl Default for TypescriptOptions {
+    fn default() -> TypescriptOptions {
+        TypescriptOptions {
+            decimal: "string".to_string(),
+            bigint: "bigint".to_string(),
+            json: "unknown".to_string(),
+            bytes: "Uint8Array".to_string(),
+        }
+    }
+}
+
+impl TypescriptOptions {
+    fn scalar(&self, scalar_type: ScalarType) -> &str {
+        match scalar_type {
+            ScalarType::Int | ScalarType::Float => "number",
+            ScalarType::String => "string",
+            ScalarType::Boolean => "boolean",
+            ScalarType::DateTime => "Date",
+            ScalarType::Json => &self.json,
+            ScalarType::Bytes => &self.bytes,
+            ScalarType::Decimal => &self.decimal,
+            ScalarType::BigInt => &self.bigint,
+        }
+    }
+}
+
+fn render_documentation(out: &mut String, documentation: Option<&str>, indent: &str) {
+    if let Some(documentation) = documentation {
+        writeln!(out, "{}/**", indent).unwrap();
+        documentation
+            .lines()
+            .for_each(|line| writeln!(out, "{} * {}", indent, line).unwrap());
+        writeln!(out, "{} */", indent).unwrap();
+    }
+}
+
+/// `T`, `T | null` or `T[]`
+fn with_arity(type_name: &str, arity: FieldArity) -> String {
+    match arity {
+        FieldArity::Required => type_name.to_string(),
+        FieldArity::Optional => format!("{} | null", type_name),
+        FieldArity::List => format!("{}[]", type_name),
+    }
+}
+
+fn field_type(
+    db: &ParserDatabase,
+    options: &TypescriptOptions,
+    r#type: ScalarFieldType,
+) -> Option<String> {
+    match r#type {
+        ScalarFieldType::BuiltInScalar(scalar_type) => {
+            Some(options.scalar(scalar_type).to_string())
+        }
+        ScalarFieldType::Enum(enum_id) => Some(db.walk(enum_id).name().to_string()),
+        ScalarFieldType::CompositeType(composite_type_id) => {
+            Some(db.walk(composite_type_id).name().to_string())
+        }
+        // The Prisma Client leaves these out as well
+        ScalarFieldType::Unsupported(_) => None,
+    }
+}
+
+fn render_model(
+    out: &mut String,
+    options: &TypescriptOptions,
+    kept: &Kept,
+    model: ModelWalker<'_>,
+) {
+    render_documentation(out, model.ast_model().documentation(), "");
+    writeln!(out, "export interface {} {{", model.name()).unwrap();
+    model.fields().for_each(|field| {
+        let line = match field.refine() {
+            RefinedFieldWalker::Scalar(scalar_field) if !scalar_field.is_ignored() => {
+                field_type(model.db, options, scalar_field.scalar_field_type()).map(|type_name| {
+                    format!(
+                        "{}: {};",
+                        scalar_field.name(),
+                        with_arity(&type_name, scalar_field.ast_field().arity)
+                    )
+                })
+            }
+            // Relations are only there when they were loaded along with the record
+            RefinedFieldWalker::Relation(relation_field)
+                if !relation_field.is_ignored()
+                    && !relation_field.related_model().is_ignored()
+                    && kept.relation_field(relation_field) =>
+            {
+                Some(format!(
+                    "{}?: {};",
+                    relation_field.name(),
+                    with_arity(
+                        relation_field.related_model().name(),
+                        relation_field.ast_field().arity
+                    )
+                ))
+            }
+            _ => None,
+        };
+        if let Some(line) = line {
+            render_documentation(out, field.ast_field().documentation(), "  ");
+            writeln!(out, "  {}", line).unwrap();
+        }
+    });
+    out.push_str("}\n");
+}
+
+pub(crate) fn render(db: &ParserDatabase, kept: &Kept, options: &TypescriptOptions) -> String {
+    let mut declarations = vec![];
+    db.walk_enums()
+        .filter(|r#enum| kept.r#enum(*r#enum))
+        .for_each(|r#enum| {
+            let mut out = String::new();
+            render_documentation(&mut out, r#enum.ast_enum().documentation(), "");
+            let values: Vec<String> = r#enum
+                .values()
+                .map(|value| format!("\"{}\"", value.name()))
+                .collect();
+            writeln!(
+                out,
+                "export type {} = {};",
+                r#enum.name(),
+                values.join(" | ")
+            )
+            .unwrap();
+            declarations.push(out);
+        });
+    db.walk_composite_types()
+        .filter(|composite_type| kept.composite_type(*composite_type))
+        .for_each(|composite_type| {
+            let mut out = String::new();
+            render_documentation(
+                &mut out,
+                composite_type.ast_composite_type().documentation(),
+                "",
+            );
+            writeln!(out, "export interface {} {{", composite_type.name()).unwrap();
+            composite_type.fields().for_each(|field| {
+                if let Some(type_name) = field_type(db, options, field.r#type()) {
+                    render_documentation(&mut out, field.documentation(), "  ");
+                    writeln!(
+                        out,
+                        "  {}: {};",
+                        field.name(),
+                        with_arity(&type_name, field.arity())
+                    )
+                    .unwrap();
+                }
+            });
+            out.push_str("}\n");
+            declarations.push(out);
+        });
+    db.walk_models()
+        .chain(db.walk_views())
+        .filter(|model| !model.is_ignored() && kept.model(*model))
+        .for_each(|model| {
+            let mut out = String::new();
+            render_model(&mut out, options, kept, model);
+            declarations.push(out);
+        });
+    declarations.join("\n")
+}
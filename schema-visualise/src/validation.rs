@@ -0,0 +1,44 @@
+use psl_core::diagnostics::{Diagnostics, Span};
+use serde::{Serialize, Serializer};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+/// A single error or warning reported while validating the schema
+#[derive(Clone, Debug, Serialize)]
+pub struct SchemaDiagnostic {
+    pub message: String,
+    #[serde(serialize_with = "crate::serialize_span")]
+    pub span: Span,
+    pub severity: Severity,
+    /// File the diagnostic is in, when the schema was read from files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+}
+
+pub fn collect(diagnostics: &Diagnostics) -> Vec<SchemaDiagnostic> {
+    let errors = diagnostics.errors().iter().map(|e| SchemaDiagnostic {
+        message: e.message().to_string(),
+        span: e.span(),
+        severity: Severity::Error,
+        file: None,
+    });
+    let warnings = diagnostics.warnings().iter().map(|w| SchemaDiagnostic {
+        message: w.message().to_string(),
+        span: w.span(),
+        severity: Severity::Warning,
+        file: None,
+    });
+    errors.chain(warnings).collect()
+}
+
+pub(crate) fn serialize_diagnostics<S: Serializer>(
+    diagnostics: &Diagnostics,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(collect(diagnostics))
+}
@@ -0,0 +1,163 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "mysql"
+  url      = env("DATABASE_URL")
+}
+
+/// Access level
+enum Role {
+  USER
+  /// Can do anything
+  ADMIN @map("admin")
+}
+
+/// Someone who can log in
+model User {
+  id        Int       @id @default(autoincrement())
+  /// Login, unique per user
+  email     String    @unique @db.VarChar(191)
+  name      String?
+  role      Role      @default(USER)
+  active    Boolean   @default(true)
+  score     Float     @default(1.5)
+  createdAt DateTime  @default(now()) @map("created_at")
+  token     String    @default(uuid())
+  posts     Post[]
+  profile   Profile?
+  groups    Group[]
+
+  @@map("users")
+}
+
+model Profile {
+  id     Int  @id @default(autoincrement())
+  user   User @relation(fields: [userId], references: [id], onDelete: Cascade)
+  userId Int  @unique
+}
+
+model Post {
+  id       Int    @id @default(autoincrement())
+  title    String @db.VarChar(255)
+  slug     String
+  author   User?  @relation(fields: [authorId], references: [id])
+  authorId Int?
+
+  @@unique([slug, title(length: 100)], map: "post_slug_title")
+  @@index([title(length: 10, sort: Desc)])
+  @@index([authorId])
+}
+
+model Group {
+  id    Int    @id
+  users User[]
+}
+
+model Membership {
+  groupId Int
+  userId  Int
+  since   DateTime @db.Date
+
+  @@id([groupId, userId])
+}
+"#;
+
+fn render() -> String {
+    SchemaVisualiser::new(SCHEMA.to_string()).render_dbml()
+}
+
+#[test]
+fn enums_and_tables() {
+    let dbml = render();
+    assert!(dbml.starts_with("Enum Role {\n  USER\n  admin [note: 'Can do anything']\n}\n"));
+    assert!(dbml.contains(
+        r#"Table users {
+  id INT [pk, increment, not null]
+  email VARCHAR(191) [unique, not null, note: 'Login, unique per user']
+  name VARCHAR(191)
+  role Role [not null, default: 'USER']
+  active TINYINT [not null, default: true]
+  score DOUBLE [not null, default: 1.5]
+  created_at DATETIME(3) [not null, default: `now()`]
+  token VARCHAR(191) [not null, default: `uuid()`]
+
+  Note: 'Someone who can log in'
+}"#
+    ));
+}
+
+#[test]
+fn indexes() {
+    let dbml = render();
+    assert!(dbml.contains(
+        r#"  indexes {
+    title [note: 'title(length: 10, sort: Desc)']
+    authorId
+    (slug, title) [unique, name: 'post_slug_title', note: 'title(length: 100)']
+  }"#
+    ));
+    assert!(dbml.contains("  indexes {\n    (groupId, userId) [pk]\n  }"));
+    assert!(dbml.contains("Table Group {\n  id INT [pk, not null]\n}"));
+}
+
+#[test]
+fn index_columns_are_never_expressions() {
+    let dbml = SchemaVisualiser::new(
+        r#"
+datasource db {
+  provider = "mysql"
+  url      = env("DATABASE_URL")
+}
+
+generator client {
+  provider        = "prisma-client-js"
+  previewFeatures = ["fullTextIndex"]
+}
+
+model Article {
+  id    Int    @id
+  title String @db.VarChar(255)
+  body  String @db.Text
+
+  @@index([title(sort: Asc), body(length: 20)])
+  @@fulltext([body])
+}
+"#
+        .to_string(),
+    )
+    .render_dbml();
+    // A backticked column would be read as a functional index on an expression
+    assert!(!dbml.contains('`'), "{}", dbml);
+    assert!(
+        dbml.contains(
+            "    (title, body) [note: 'title(sort: Asc), body(length: 20)']\n    body [note: 'fulltext']\n"
+        ),
+        "{}",
+        dbml
+    );
+}
+
+#[test]
+fn references() {
+    let dbml = render();
+    assert!(dbml.contains("Ref: Profile.userId - users.id [delete: cascade, update: cascade]\n"));
+    assert!(dbml.contains("Ref: Post.authorId > users.id [delete: set null, update: cascade]\n"));
+    assert!(dbml.contains("Ref: Group.id <> users.id\n"));
+}
+
+#[test]
+fn relation_mode_prisma() {
+    let dbml = SchemaVisualiser::new(SCHEMA.replace(
+        "provider = \"mysql\"",
+        "provider     = \"mysql\"\n  relationMode = \"prisma\"",
+    ))
+    .render_dbml();
+    assert!(dbml.contains("Ref: Post.authorId > users.id\n"));
+}
+
+#[test]
+fn invalid_schema() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Nope @id\n}\n".to_string());
+    assert_eq!(visualiser.render_dbml(), "");
+}
@@ -0,0 +1,81 @@
+use prismaviz::{PrismaVizModelKind, SchemaVisualiser};
+
+const SCHEMA: &str = r#"
+enum Role {
+  USER  @map("user")
+  ADMIN
+}
+
+type Address {
+  street String
+  zip    String?
+}
+
+model User {
+  id      Int     @id
+  role    Role
+}
+
+view UserInfo {
+  id    Int    @unique
+  email String
+}
+"#;
+
+#[test]
+fn visualises_every_top_level_declaration() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    visualiser.parse();
+
+    let declarations = visualiser
+        .models
+        .iter()
+        .map(|m| (m.name.as_str(), m.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        declarations,
+        vec![
+            ("Role", PrismaVizModelKind::Enum),
+            ("Address", PrismaVizModelKind::CompositeType),
+            ("User", PrismaVizModelKind::Model),
+            ("UserInfo", PrismaVizModelKind::View),
+        ]
+    );
+}
+
+#[test]
+fn enum_values_keep_their_mapped_names() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    visualiser.parse();
+
+    let role = &visualiser.models[0];
+    let values = role
+        .fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.constraints.as_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        values,
+        vec![("USER", vec!["map(user)".to_string()]), ("ADMIN", vec![])]
+    );
+}
+
+#[test]
+fn composite_type_fields_are_listed() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    visualiser.parse();
+
+    let address = &visualiser.models[1];
+    let fields = address
+        .fields
+        .iter()
+        .map(|f| (f.name.as_str(), f.r#type.resolve_with_modifier()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        fields,
+        vec![
+            ("street", "String".to_string()),
+            ("zip", "String?".to_string())
+        ]
+    );
+}
@@ -0,0 +1,40 @@
+use prismaviz::{SchemaVisualiser, Severity};
+
+#[test]
+fn keeps_validation_errors() {
+    let schema = r#"
+model User {
+  id   Int   @id
+  name Strin
+}
+"#;
+    let mut visualiser = SchemaVisualiser::new(schema.to_string());
+    visualiser.parse();
+
+    assert!(visualiser.has_errors());
+    let diagnostics = visualiser.schema_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(
+        &schema[diagnostics[0].span.start..diagnostics[0].span.end],
+        "Strin"
+    );
+    assert!(visualiser
+        .diagnostics_to_pretty_string("schema.prisma")
+        .contains("schema.prisma:4"));
+}
+
+#[test]
+fn valid_schema_has_no_diagnostics() {
+    let schema = r#"
+model User {
+  id   Int    @id
+  name String
+}
+"#;
+    let mut visualiser = SchemaVisualiser::new(schema.to_string());
+    visualiser.parse();
+
+    assert!(!visualiser.has_errors());
+    assert!(visualiser.schema_diagnostics().is_empty());
+}
@@ -0,0 +1,156 @@
+use prismaviz::diff::{ChangeKind, ChangeSubject, SchemaDiff};
+
+const OLD: &str = r#"
+enum Role {
+  USER
+  ADMIN
+}
+
+model User {
+  id      Int      @id
+  email   String   @unique
+  name    String
+  role    Role     @default(USER)
+  profile Profile?
+
+  @@index([name])
+}
+
+model Profile {
+  id     Int  @id
+  user   User @relation(fields: [userId], references: [id])
+  userId Int  @unique
+}
+
+model Legacy {
+  id Int @id
+}
+"#;
+
+const NEW: &str = r#"
+enum Role {
+  USER
+  ADMIN
+  OWNER
+}
+
+model Account {
+  id       Int       @id
+  email    String?   @unique
+  fullName String    @map("name")
+  role     Role      @default(ADMIN)
+  profiles Profile[]
+
+  @@index([fullName])
+  @@map("User")
+}
+
+model Profile {
+  id     Int     @id
+  user   Account @relation(fields: [userId], references: [id])
+  userId Int
+}
+"#;
+
+fn find<'a>(diff: &'a SchemaDiff, parent: &str, name: &str) -> Vec<(ChangeKind, &'a [String])> {
+    diff.changes
+        .iter()
+        .filter(|c| c.parent == parent && c.name == name)
+        .map(|c| (c.kind, c.details.as_slice()))
+        .collect()
+}
+
+#[test]
+fn reports_added_removed_and_renamed_declarations() {
+    let diff = SchemaDiff::new(OLD, NEW);
+    assert!(!diff.has_errors());
+
+    assert_eq!(find(&diff, "Legacy", "Legacy")[0].0, ChangeKind::Removed);
+    assert_eq!(
+        find(&diff, "Account", "Account"),
+        vec![(
+            ChangeKind::Renamed,
+            &[
+                "renamed from User".to_string(),
+                "@@map(\"User\") added".to_string()
+            ][..]
+        )]
+    );
+    assert_eq!(
+        find(&diff, "Account", "fullName"),
+        vec![(
+            ChangeKind::Renamed,
+            &[
+                "renamed from name".to_string(),
+                "@map(\"name\") added".to_string()
+            ][..]
+        )]
+    );
+    assert_eq!(find(&diff, "Role", "OWNER")[0].0, ChangeKind::Added);
+}
+
+#[test]
+fn reports_type_attribute_index_and_cardinality_changes() {
+    let diff = SchemaDiff::new(OLD, NEW);
+
+    assert_eq!(find(&diff, "Account", "email")[0].1, ["String -> String?"]);
+    assert_eq!(
+        find(&diff, "Account", "role")[0].1,
+        ["@default(USER) -> @default(ADMIN)"]
+    );
+    assert_eq!(find(&diff, "Profile", "userId")[0].1, ["@unique removed"]);
+    assert_eq!(
+        find(&diff, "Profile", "user")[0].1,
+        ["User -> Account", "1:1 -> 1:n"]
+    );
+    assert_eq!(
+        find(&diff, "Account", "@@index([name])")[0].0,
+        ChangeKind::Removed
+    );
+    assert_eq!(
+        find(&diff, "Account", "@@index([fullName])")[0].0,
+        ChangeKind::Added
+    );
+
+    let profile = diff.changes.iter().find(|c| c.name == "profile").unwrap();
+    assert_eq!(profile.subject, ChangeSubject::Relation);
+    assert!(profile.old_span.is_some() && profile.new_span.is_none());
+}
+
+#[test]
+fn renders_markdown_for_pull_requests() {
+    let unchanged = SchemaDiff::new(OLD, OLD);
+    assert_eq!(
+        unchanged.render_markdown(),
+        "### Schema changes\n\nNo changes.\n"
+    );
+
+    let diff = SchemaDiff::new(OLD, NEW);
+    assert!(diff
+        .render_markdown()
+        .contains("| modified | field | `Account` | `email` | `String -> String?` |"));
+}
+
+#[test]
+fn reports_schemas_with_errors_instead_of_no_changes() {
+    let invalid = "model User {\n  id Nope @id\n}\n";
+    let diff = SchemaDiff::new(OLD, invalid);
+    assert!(diff.has_errors());
+    assert_eq!(
+        diff.cannot_diff(),
+        Some("Cannot diff: the new schema has errors.")
+    );
+    let markdown = diff.render_markdown();
+    assert!(markdown.starts_with(
+        "### Schema changes\n\nCannot diff: the new schema has errors.\n\n- new schema: "
+    ));
+    assert!(markdown.contains("Nope"), "{}", markdown);
+    assert!(!markdown.contains("No changes."));
+
+    let diff = SchemaDiff::new(invalid, invalid);
+    assert_eq!(
+        diff.cannot_diff(),
+        Some("Cannot diff: the old and new schemas have errors.")
+    );
+    assert_eq!(SchemaDiff::new(OLD, NEW).cannot_diff(), None);
+}
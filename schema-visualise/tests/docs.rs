@@ -0,0 +1,95 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+generator client {
+  provider        = "prisma-client-js"
+  previewFeatures = ["views"]
+}
+
+/// Access level
+enum Role {
+  USER
+  /// Can do anything
+  ADMIN @map("admin")
+
+  @@map("role")
+}
+
+/// Someone who can log in
+model User {
+  id        Int      @id @default(autoincrement())
+  /// Login, unique per user
+  email     String   @unique @db.VarChar(191)
+  name      String?
+  role      Role     @default(USER)
+  createdAt DateTime @default(now()) @map("created_at")
+  updatedAt DateTime @updatedAt
+  /// Everything they wrote
+  posts     Post[]
+
+  @@map("users")
+}
+
+model Post {
+  id       Int    @id
+  title    String
+  author   User   @relation(fields: [authorId], references: [id], onDelete: Cascade)
+  authorId Int
+
+  @@index([authorId])
+}
+
+view Author {
+  id    Int    @unique
+  email String
+}
+"#;
+
+fn render() -> String {
+    SchemaVisualiser::new(SCHEMA.to_string()).render_docs()
+}
+
+#[test]
+fn models_with_field_tables() {
+    let docs = render();
+    assert!(docs.starts_with(
+        "# Data dictionary\n\n## Models\n\n### User\n\nSomeone who can log in\n\nTable: `users`\n"
+    ));
+    assert!(docs.contains(
+        "| `email` | `String @db.VarChar(191)` | No |  | Unique | `email` | Login, unique per user |\n"
+    ));
+    assert!(docs.contains("| `id` | `Int` | No | `autoincrement()` | PK | `id` |  |\n"));
+    assert!(docs.contains("| `name` | `String` | Yes |  |  | `name` |  |\n"));
+    assert!(docs.contains("| `createdAt` | `DateTime` | No | `now()` |  | `created_at` |  |\n"));
+    assert!(docs.contains("| `authorId` | `Int` | No |  | FK, Indexed | `authorId` |  |\n"));
+}
+
+#[test]
+fn relations() {
+    let docs = render();
+    assert!(docs.contains("- `posts`: one-to-many [`Post`](#post) — Everything they wrote\n"));
+    assert!(docs.contains(
+        "- `author`: many-to-one [`User`](#user) through `authorId` referencing `id`, on delete Cascade\n"
+    ));
+}
+
+#[test]
+fn views_and_enums() {
+    let docs = render();
+    assert!(docs.contains("## Views\n\n### Author\n\nView: `Author`\n"));
+    assert!(docs.contains(
+        "## Enums\n\n### Role\n\nAccess level\n\nType: `role`\n\n| Value | Database value | Description |\n"
+    ));
+    assert!(docs.ends_with("| `ADMIN` | `admin` | Can do anything |\n"));
+}
+
+#[test]
+fn invalid_schema() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Nope @id\n}\n".to_string());
+    assert_eq!(visualiser.render_docs(), "");
+}
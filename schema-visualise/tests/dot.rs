@@ -0,0 +1,28 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+model User {
+  id    Int    @id
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+"#;
+
+#[test]
+fn renders_record_nodes_and_foreign_key_edges() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let graph = visualiser.render_dot();
+
+    assert!(graph.starts_with("digraph schema {\n"));
+    assert!(graph.contains(r#"<TD PORT="authorId" ALIGN="LEFT">authorId</TD><TD ALIGN="LEFT">Int</TD><TD ALIGN="LEFT">FK</TD>"#));
+    assert!(
+        graph.contains(r#"<TD PORT="posts" ALIGN="LEFT">posts</TD><TD ALIGN="LEFT">Post[]</TD>"#)
+    );
+    assert!(graph.contains(r#""Post":"authorId" -> "User":"id" [label="author"];"#));
+    assert!(graph.ends_with("}\n"));
+}
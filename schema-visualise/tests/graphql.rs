@@ -0,0 +1,120 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+/// Access level
+enum Role {
+  USER
+  /// Can do anything
+  ADMIN
+}
+
+model User {
+  id      Int                   @id
+  /// Login, unique per user
+  email   String                @unique
+  name    String?
+  roles   Role[]
+  balance Decimal
+  meta    Json?
+  created DateTime
+  secret  String                @ignore
+  geo     Unsupported("point")?
+  posts   Post[]
+  profile Profile?
+}
+
+model Profile {
+  id     Int  @id
+  user   User @relation(fields: [userId], references: [id])
+  userId Int  @unique
+}
+
+model Post {
+  id       Int   @id
+  author   User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+
+model Legacy {
+  id Int
+
+  @@ignore
+}
+"#;
+
+#[test]
+fn types_and_enums() {
+    let graphql = SchemaVisualiser::new(SCHEMA.to_string()).render_graphql();
+    assert!(graphql.starts_with("scalar DateTime\nscalar Decimal\nscalar Json\n\n"));
+    assert!(graphql.contains(
+        r#""""
+Access level
+"""
+enum Role {
+  USER
+  """
+  Can do anything
+  """
+  ADMIN
+}"#
+    ));
+    assert!(graphql.contains(
+        r#"type User {
+  id: Int!
+  """
+  Login, unique per user
+  """
+  email: String!
+  name: String
+  roles: [Role!]!
+  balance: Decimal!
+  meta: Json
+  created: DateTime!
+  posts: [Post!]!
+  profile: Profile
+}"#
+    ));
+    assert!(graphql.contains("  user: User!\n  userId: Int!\n"));
+    assert!(graphql.contains("  author: User\n  authorId: Int\n"));
+    assert!(!graphql.contains("Legacy"));
+    assert!(!graphql.contains("BigInt"));
+}
+
+#[test]
+fn composite_types() {
+    let graphql = SchemaVisualiser::new(
+        r#"
+datasource db {
+  provider = "mongodb"
+  url      = env("DATABASE_URL")
+}
+
+type Address {
+  street String
+  zip    String?
+}
+
+model Customer {
+  id        String    @id @map("_id") @db.ObjectId
+  addresses Address[]
+  avatar    Bytes?
+}
+"#
+        .to_string(),
+    )
+    .render_graphql();
+    assert!(graphql.starts_with("scalar Bytes\n\n"));
+    assert!(graphql.contains("type Address {\n  street: String!\n  zip: String\n}"));
+    assert!(graphql.contains("  addresses: [Address!]!\n  avatar: Bytes\n"));
+}
+
+#[test]
+fn invalid_schema() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Nope @id\n}\n".to_string());
+    assert_eq!(visualiser.render_graphql(), "");
+}
@@ -0,0 +1,102 @@
+use prismaviz::highlight::{Highlighter, TokenKind};
+
+const SCHEMA: &str = r#"// Shop schema
+generator client {
+  provider = "prisma-client-js"
+}
+
+/// A customer
+model User {
+  id    Int     @id @default(autoincrement())
+  email String? @unique @db.VarChar(200)
+  role  Role    @default(USER)
+}
+
+enum Role {
+  USER
+  ADMIN @map("admin")
+}
+"#;
+
+fn classified(highlighter: &Highlighter, kind: TokenKind) -> Vec<String> {
+    highlighter
+        .tokens()
+        .iter()
+        .filter(|token| token.kind == kind)
+        .map(|token| SCHEMA[token.span.start..token.span.end].to_string())
+        .collect()
+}
+
+#[test]
+fn classifies_every_kind_of_token() {
+    let highlighter = Highlighter::new(SCHEMA);
+
+    assert_eq!(
+        classified(&highlighter, TokenKind::Keyword),
+        vec!["generator", "model", "enum"]
+    );
+    assert_eq!(
+        classified(&highlighter, TokenKind::Comment),
+        vec!["// Shop schema"]
+    );
+    assert_eq!(
+        classified(&highlighter, TokenKind::DocComment),
+        vec!["/// A customer"]
+    );
+    assert_eq!(
+        classified(&highlighter, TokenKind::Type),
+        vec!["Int", "String", "Role"]
+    );
+    assert_eq!(
+        classified(&highlighter, TokenKind::Attribute),
+        vec![
+            "@id",
+            "@default",
+            "@unique",
+            "@db.VarChar",
+            "@default",
+            "@map"
+        ]
+    );
+    assert_eq!(
+        classified(&highlighter, TokenKind::String),
+        vec!["\"prisma-client-js\"", "\"admin\""]
+    );
+    assert_eq!(classified(&highlighter, TokenKind::Number), vec!["200"]);
+}
+
+#[test]
+fn renders_a_single_declaration() {
+    let highlighter = Highlighter::new(SCHEMA);
+    let span = highlighter.declaration_span("Role").unwrap();
+
+    assert_eq!(
+        highlighter.html_for(span),
+        concat!(
+            r#"<pre class="schema-code"><span class="token-keyword">enum</span> "#,
+            r#"<span class="token-identifier">Role</span> <span class="token-punctuation">{</span>"#,
+            "\n  ",
+            r#"<span class="token-identifier">USER</span>"#,
+            "\n  ",
+            r#"<span class="token-identifier">ADMIN</span> <span class="token-attribute">@map</span>"#,
+            r#"<span class="token-punctuation">(</span><span class="token-string">&quot;admin&quot;</span>"#,
+            r#"<span class="token-punctuation">)</span>"#,
+            "\n",
+            r#"<span class="token-punctuation">}</span></pre>"#,
+        )
+    );
+}
+
+#[test]
+fn shows_a_declaration_in_terminal_colours() {
+    let mut visualiser = prismaviz::SchemaVisualiser::new(SCHEMA.to_string());
+
+    colored::control::set_override(true);
+    let coloured = visualiser.render_source("Role").unwrap();
+    assert!(coloured.starts_with("\u{1b}[1;35menum\u{1b}[0m Role"));
+
+    colored::control::set_override(false);
+    let plain = visualiser.render_source("Role").unwrap();
+    assert_eq!(plain, visualiser.models[1].code);
+    assert!(visualiser.render_source("Post").is_none());
+}
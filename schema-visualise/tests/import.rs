@@ -0,0 +1,230 @@
+use prismaviz::{import::import, sql::Dialect, SchemaVisualiser};
+
+fn assert_valid(schema: &str) {
+    let validated = psl::validate(schema.to_string().into());
+    assert!(
+        !validated.diagnostics.has_errors(),
+        "{}",
+        validated
+            .diagnostics
+            .to_pretty_string("schema.prisma", schema)
+    );
+}
+
+const POSTGRES: &str = r#"
+CREATE TYPE public."Role" AS ENUM ('USER', 'admin', 'in-review');
+
+CREATE TABLE public.users (
+    id integer NOT NULL,
+    email character varying(200) NOT NULL,
+    display_name text,
+    role public."Role" DEFAULT 'USER'::public."Role" NOT NULL,
+    created_at timestamp(3) without time zone DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    tags text[],
+    location point,
+    CONSTRAINT users_pkey PRIMARY KEY (id)
+);
+
+CREATE TABLE public.posts (
+    id bigserial PRIMARY KEY,
+    title text DEFAULT 'It''s new' NOT NULL,
+    author_id integer NOT NULL REFERENCES public.users(id) ON DELETE CASCADE,
+    editor_id integer
+);
+
+CREATE TABLE audit_log (message text);
+
+CREATE UNIQUE INDEX users_email_key ON public.users USING btree (email);
+CREATE INDEX posts_title_idx ON public.posts USING btree (title DESC, author_id);
+ALTER TABLE ONLY public.posts
+    ADD CONSTRAINT posts_editor_fk FOREIGN KEY (editor_id) REFERENCES public.users(id) ON DELETE SET NULL;
+"#;
+
+#[test]
+fn postgres_dump() {
+    let schema = import(POSTGRES, Dialect::Postgres).unwrap();
+    assert_valid(&schema);
+    assert!(schema.contains(r#"provider = "postgresql""#));
+    assert!(schema.contains("model Users {"));
+    assert!(schema.contains(r#"@@map("users")"#));
+    assert!(schema.contains(r#"displayName String?               @map("display_name")"#));
+    assert!(schema.contains("role        Role                  @default(USER)"));
+    assert!(schema.contains(r#"email       String                @unique @db.VarChar(200)"#));
+    assert!(schema.contains(r#"location    Unsupported("point")?"#));
+    assert!(schema.contains("tags        String[]"));
+    assert!(schema.contains("id       BigInt @id @default(autoincrement())"));
+    assert!(schema.contains(r#"title    String @default("It's new")"#));
+    assert!(schema.contains(r#"in_review @map("in-review")"#));
+}
+
+#[test]
+fn foreign_keys_become_relations() {
+    let schema = import(POSTGRES, Dialect::Postgres).unwrap();
+    assert!(schema.contains(r#"author   Users  @relation("posts_author_id_fkey", fields: [authorId], references: [id], onDelete: Cascade)"#));
+    assert!(schema.contains(r#"editor   Users? @relation("posts_editor_fk", fields: [editorId], references: [id], onDelete: SetNull, map: "posts_editor_fk")"#));
+    assert!(
+        schema.contains(r#"postsAuthor Posts[]               @relation("posts_author_id_fkey")"#)
+    );
+    assert!(schema.contains(r#"@@index([title(sort: Desc), authorId], map: "posts_title_idx")"#));
+}
+
+#[test]
+fn tables_without_a_key_are_ignored() {
+    let schema = import(POSTGRES, Dialect::Postgres).unwrap();
+    assert!(schema.contains(
+        r#"model AuditLog {
+  message String?
+
+  @@map("audit_log")
+  @@ignore
+}"#
+    ));
+}
+
+#[test]
+fn relations_to_ignored_tables_are_ignored() {
+    let schema = import(
+        "CREATE TABLE a (id int PRIMARY KEY);\nCREATE TABLE b (a_id int REFERENCES a(id));\n",
+        Dialect::Postgres,
+    )
+    .unwrap();
+    assert_valid(&schema);
+    assert!(schema.contains("b  B[] @ignore"), "{}", schema);
+}
+
+/// The line declaring `field` in `schema`, with its spacing collapsed
+fn field_line(schema: &str, field: &str) -> String {
+    let line = schema
+        .lines()
+        .find(|line| line.split_whitespace().next() == Some(field))
+        .unwrap_or_else(|| panic!("no field {} in {}", field, schema));
+    line.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[test]
+fn table_level_primary_keys_are_required() {
+    [
+        Dialect::Postgres,
+        Dialect::Cockroachdb,
+        Dialect::Mysql,
+        Dialect::Sqlserver,
+        Dialect::Sqlite,
+    ]
+    .into_iter()
+    .for_each(|dialect| {
+        let single = import(
+            "CREATE TABLE t (id int, name text, PRIMARY KEY (id));\n",
+            dialect,
+        )
+        .unwrap();
+        assert_valid(&single);
+        assert!(field_line(&single, "id").ends_with("Int @id"), "{}", single);
+        assert!(field_line(&single, "name").starts_with("name String?"));
+
+        let composite = import(
+            "CREATE TABLE tags (a int, b int, CONSTRAINT tags_pkey PRIMARY KEY (a, b));\n",
+            dialect,
+        )
+        .unwrap();
+        assert_valid(&composite);
+        assert!(!field_line(&composite, "a").contains('?'), "{}", composite);
+        assert!(!field_line(&composite, "b").contains('?'), "{}", composite);
+        assert!(composite.contains("@@id([a, b]"), "{}", composite);
+    });
+}
+
+#[test]
+fn mysql_dump() {
+    let schema = import(
+        r#"
+CREATE TABLE `users` (
+  `id` int(11) NOT NULL AUTO_INCREMENT,
+  `email` varchar(191) COLLATE utf8mb4_unicode_ci NOT NULL,
+  `is_admin` tinyint(1) NOT NULL DEFAULT '0',
+  `status` enum('active','banned') NOT NULL DEFAULT 'active',
+  `score` int unsigned NOT NULL DEFAULT '10',
+  `name` varchar(100) CHARACTER SET utf8mb4 DEFAULT NULL,
+  PRIMARY KEY (`id`),
+  UNIQUE KEY `users_email_key` (`email`),
+  KEY `users_name_idx` (`name`(10))
+) ENGINE=InnoDB AUTO_INCREMENT=3 DEFAULT CHARSET=utf8mb4;
+
+CREATE TABLE `profiles` (
+  `user_id` int NOT NULL,
+  PRIMARY KEY (`user_id`),
+  CONSTRAINT `profiles_user_id_fkey` FOREIGN KEY (`user_id`) REFERENCES `users` (`id`)
+);
+"#,
+        Dialect::Mysql,
+    )
+    .unwrap();
+    assert_valid(&schema);
+    assert!(schema.contains("id       Int         @id @default(autoincrement())"));
+    assert!(schema.contains(r#"isAdmin  Boolean     @default(false) @map("is_admin")"#));
+    assert!(schema.contains("status   UsersStatus @default(active)"));
+    assert!(schema.contains("score    Int         @default(10) @db.UnsignedInt"));
+    assert!(schema.contains("profiles Profiles?"));
+    assert!(schema.contains("@@index([name(length: 10)])"));
+    assert!(schema.contains("enum UsersStatus {"));
+}
+
+#[test]
+fn sqlite_dump() {
+    let schema = import(
+        r#"
+CREATE TABLE "User" (
+    "id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,
+    "email" TEXT NOT NULL,
+    "parentId" INTEGER,
+    CONSTRAINT "User_parentId_fkey" FOREIGN KEY ("parentId") REFERENCES "User" ("id") ON DELETE SET NULL ON UPDATE CASCADE
+);
+CREATE UNIQUE INDEX "User_email_key" ON "User"("email");
+"#,
+        Dialect::Sqlite,
+    )
+    .unwrap();
+    assert_valid(&schema);
+    assert!(schema.contains("email      String @unique"));
+    assert!(schema.contains(r#"parent     User?  @relation("User_parentId_fkey", fields: [parentId], references: [id], onDelete: SetNull, onUpdate: Cascade)"#));
+    assert!(schema.contains(r#"userParent User[] @relation("User_parentId_fkey")"#));
+}
+
+#[test]
+fn generated_sql_imports_back() {
+    let schema = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+model User {
+  id     Int     @id @default(autoincrement())
+  email  String  @unique
+  posts  Post[]
+  groups Group[]
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+
+model Group {
+  id    Int    @id
+  users User[]
+}
+"#;
+    [
+        Dialect::Postgres,
+        Dialect::Cockroachdb,
+        Dialect::Mysql,
+        Dialect::Sqlserver,
+        Dialect::Sqlite,
+    ]
+    .into_iter()
+    .for_each(|dialect| {
+        let ddl = SchemaVisualiser::new(schema.to_string()).render_sql(dialect);
+        assert_valid(&import(&ddl, dialect).unwrap());
+    });
+}
@@ -0,0 +1,59 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+model User {
+  id    Int    @id @default(autoincrement())
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+"#;
+
+#[test]
+fn renders_the_visualise_document() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let document: serde_json::Value = serde_json::from_str(&visualiser.render_json()).unwrap();
+
+    assert_eq!(document["schema"], SCHEMA);
+    assert_eq!(document["result"][0]["name"], "User");
+    assert_eq!(document["result"][0]["span"]["start"], 1);
+
+    let id = &document["result"][0]["fields"][0];
+    assert_eq!(id["name"], "id");
+    assert_eq!(id["type"], "Int");
+    assert_eq!(id["is_index"], "false");
+    assert_eq!(
+        id["constraints"],
+        serde_json::json!(["id", "default(autoincrement())"])
+    );
+
+    let author = &document["result"][1]["fields"][1];
+    assert_eq!(author["type"], "User");
+    assert_eq!(
+        author["relation_ship_fields"],
+        serde_json::json!(["authorId"])
+    );
+    assert_eq!(
+        author["relation_ship_references"],
+        serde_json::json!(["id"])
+    );
+}
+
+#[test]
+fn gives_every_model_an_id_like_the_service() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let document: serde_json::Value = serde_json::from_str(&visualiser.render_json()).unwrap();
+    let ids: Vec<&str> = document["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|model| model["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(ids.len(), 2);
+    assert_ne!(ids[0], ids[1]);
+    assert!(ids.iter().all(|id| id.len() == 36));
+}
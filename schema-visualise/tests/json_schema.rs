@@ -0,0 +1,133 @@
+use prismaviz::SchemaVisualiser;
+use serde_json::json;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+
+/// Someone who can log in
+model User {
+  id        Int                   @id @default(autoincrement())
+  /// Login, unique per user
+  email     String                @unique
+  name      String?
+  role      Role                  @default(USER)
+  previous  Role?
+  tags      String[]
+  balance   Decimal
+  meta      Json?
+  createdAt DateTime              @default(now())
+  secret    String                @ignore
+  geo       Unsupported("point")?
+  posts     Post[]
+}
+
+model Post {
+  id       Int   @id
+  author   User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+
+model Legacy {
+  id Int
+
+  @@ignore
+}
+"#;
+
+#[test]
+fn models() {
+    let schema = SchemaVisualiser::new(SCHEMA.to_string())
+        .json_schema()
+        .unwrap();
+    assert_eq!(
+        schema["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
+    assert_eq!(
+        schema["$defs"]["User"],
+        json!({
+            "type": "object",
+            "description": "Someone who can log in",
+            "properties": {
+                "id": { "type": "integer" },
+                "email": { "type": "string", "description": "Login, unique per user" },
+                "name": { "type": ["string", "null"] },
+                "role": { "type": "string", "enum": ["USER", "ADMIN"] },
+                "previous": { "type": ["string", "null"], "enum": ["USER", "ADMIN", null] },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "balance": { "type": "string" },
+                "meta": {},
+                "createdAt": { "type": "string", "format": "date-time" },
+                "posts": { "type": "array", "items": { "$ref": "#/$defs/Post" } }
+            },
+            "required": ["email", "balance"]
+        })
+    );
+    assert_eq!(
+        schema["$defs"]["Post"]["properties"]["author"],
+        json!({ "anyOf": [{ "$ref": "#/$defs/User" }, { "type": "null" }] })
+    );
+    assert_eq!(schema["$defs"]["Post"]["required"], json!(["id"]));
+    assert!(schema["$defs"].get("Legacy").is_none());
+}
+
+#[test]
+fn composite_types() {
+    let schema = SchemaVisualiser::new(
+        r#"
+datasource db {
+  provider = "mongodb"
+  url      = env("DATABASE_URL")
+}
+
+type Address {
+  street  String
+  zip     String?
+  country String  @default("NL")
+}
+
+model Customer {
+  id      String  @id @map("_id") @db.ObjectId
+  address Address
+}
+"#
+        .to_string(),
+    )
+    .json_schema()
+    .unwrap();
+    assert_eq!(
+        schema["$defs"]["Address"],
+        json!({
+            "type": "object",
+            "properties": {
+                "street": { "type": "string" },
+                "zip": { "type": ["string", "null"] },
+                "country": { "type": "string" }
+            },
+            "required": ["street"]
+        })
+    );
+    assert_eq!(
+        schema["$defs"]["Customer"]["properties"]["address"],
+        json!({ "$ref": "#/$defs/Address" })
+    );
+    assert_eq!(
+        schema["$defs"]["Customer"]["required"],
+        json!(["id", "address"])
+    );
+}
+
+#[test]
+fn invalid_schema() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Nope @id\n}\n".to_string());
+    assert!(visualiser.json_schema().is_none());
+    assert_eq!(visualiser.render_json_schema(), "");
+}
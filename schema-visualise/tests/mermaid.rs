@@ -0,0 +1,97 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+model User {
+  id      Int      @id
+  email   String   @unique
+  posts   Post[]
+  profile Profile?
+}
+
+model Profile {
+  id     Int  @id
+  user   User @relation(fields: [userId], references: [id])
+  userId Int  @unique
+}
+
+model Post {
+  id       Int   @id
+  author   User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+"#;
+
+#[test]
+fn renders_entities_with_key_markers() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let diagram = visualiser.render_mermaid();
+
+    assert!(diagram.starts_with("erDiagram\n"));
+    assert!(diagram.contains("        Int id PK\n"));
+    assert!(diagram.contains("        String email UK\n"));
+    assert!(diagram.contains("        Int userId FK, UK\n"));
+    assert!(!diagram.contains("Post[] posts"));
+}
+
+#[test]
+fn renders_relation_cardinality() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let diagram = visualiser.render_mermaid();
+
+    assert!(diagram.contains(r#"    Profile |o--|| User : "user""#));
+    assert!(diagram.contains(r#"    Post }o--o| User : "author""#));
+}
+
+#[test]
+fn marks_only_single_field_uniques() {
+    let mut visualiser = SchemaVisualiser::new(
+        r#"
+model Membership {
+  id     Int    @id
+  teamId Int
+  userId Int
+  slug   String
+
+  @@unique([teamId, userId])
+  @@unique([slug])
+}
+"#
+        .to_string(),
+    );
+    let diagram = visualiser.render_mermaid();
+
+    assert!(diagram.contains("        Int teamId\n"));
+    assert!(diagram.contains("        Int userId\n"));
+    assert!(diagram.contains("        String slug UK\n"));
+}
+
+#[test]
+fn writes_unsupported_types_as_plain_tokens() {
+    let mut visualiser = SchemaVisualiser::new(
+        r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+model Place {
+  id       Int                      @id
+  location Unsupported("point")?
+  areas    Unsupported("polygon[]")[]
+}
+"#
+        .to_string(),
+    );
+    let diagram = visualiser.render_mermaid();
+
+    assert!(
+        diagram.contains("        Unsupported_point location\n"),
+        "{}",
+        diagram
+    );
+    assert!(
+        diagram.contains("        Unsupported_polygon[] areas\n"),
+        "{}",
+        diagram
+    );
+}
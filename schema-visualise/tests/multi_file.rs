@@ -0,0 +1,153 @@
+use prismaviz::{source, SchemaFile, SchemaVisualiser, SourceMap};
+use psl_core::diagnostics::Span;
+
+fn files() -> Vec<SchemaFile> {
+    vec![
+        SchemaFile::new(
+            "schema.prisma",
+            r#"datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+model User {
+  id       Int       @id
+  role     Role
+  invoices Invoice[]
+}"#,
+        ),
+        SchemaFile::new(
+            "billing/invoice.prisma",
+            "model Invoice {\n  id     Int  @id\n  user   User @relation(fields: [userId], references: [id])\n  userId Int\n}\n",
+        ),
+        SchemaFile::new("billing/enums.prisma", "enum Role {\n  USER\n  ADMIN\n}\n"),
+    ]
+}
+
+#[test]
+fn resolves_relations_and_enums_across_files() {
+    let mut visualiser = SchemaVisualiser::from_files(&files());
+    visualiser.parse();
+    assert!(
+        !visualiser.has_errors(),
+        "{:?}",
+        visualiser.schema_diagnostics()
+    );
+    let invoice = visualiser
+        .models
+        .iter()
+        .find(|model| model.name == "Invoice")
+        .unwrap();
+    let user = invoice
+        .fields
+        .iter()
+        .find(|field| field.name == "user")
+        .unwrap();
+    assert_eq!(user.relation_ships.fields(), ["userId"]);
+    assert!(visualiser
+        .render_dbml()
+        .contains("Ref: Invoice.userId > User.id"));
+}
+
+#[test]
+fn names_the_file_of_every_declaration() {
+    let mut visualiser = SchemaVisualiser::from_files(&files());
+    visualiser.parse();
+    let files: Vec<(&str, Option<&str>)> = visualiser
+        .models
+        .iter()
+        .map(|model| (model.name.as_str(), model.file.as_deref()))
+        .collect();
+    assert_eq!(
+        files,
+        [
+            ("User", Some("schema.prisma")),
+            ("Invoice", Some("billing/invoice.prisma")),
+            ("Role", Some("billing/enums.prisma")),
+        ]
+    );
+    let json: serde_json::Value = serde_json::from_str(&visualiser.render_json()).unwrap();
+    assert_eq!(json["files"][1]["name"], "billing/invoice.prisma");
+    assert_eq!(json["result"][1]["file"], "billing/invoice.prisma");
+}
+
+#[test]
+fn separates_files_not_ending_with_a_newline() {
+    let (schema, source_map) = SourceMap::concat(&files());
+    // The first file has no trailing newline
+    assert!(schema.contains("}\nmodel Invoice {"));
+    let spans: Vec<Span> = source_map.files().map(|(_, span)| span).collect();
+    assert_eq!(spans[0].end + 1, spans[1].start);
+    assert_eq!(spans[1].end, spans[2].start);
+    assert_eq!(spans[2].end, schema.len());
+}
+
+#[test]
+fn reports_diagnostics_in_the_file_they_are_in() {
+    let mut files = files();
+    files[1].contents = files[1]
+        .contents
+        .replace("userId Int\n", "userId Int\n  total  Money\n");
+    let mut visualiser = SchemaVisualiser::from_files(&files);
+    visualiser.parse();
+    let diagnostics = visualiser.schema_diagnostics();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].file.as_deref(),
+        Some("billing/invoice.prisma")
+    );
+    let (file, span) = visualiser.files.locate(diagnostics[0].span).unwrap();
+    assert_eq!(file, "billing/invoice.prisma");
+    assert_eq!(&files[1].contents[span.start..span.end], "Money");
+
+    colored::control::set_override(false);
+    let pretty = visualiser.diagnostics_to_pretty_string("unused");
+    assert!(
+        pretty.contains("-->  billing/invoice.prisma:5"),
+        "{}",
+        pretty
+    );
+    assert!(pretty.contains("5 |   total  Money"), "{}", pretty);
+}
+
+#[test]
+fn a_single_schema_has_no_files() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Foo @id\n}\n".to_string());
+    visualiser.parse();
+    assert!(visualiser.schema_diagnostics()[0].file.is_none());
+    let json: serde_json::Value = serde_json::from_str(&visualiser.render_json()).unwrap();
+    assert!(json.get("files").is_none());
+    assert!(json["result"][0].get("file").is_none());
+}
+
+#[test]
+fn reads_the_prisma_files_of_a_directory_in_name_order() {
+    let directory =
+        std::env::temp_dir().join(format!("prismaviz-multi-file-{}", std::process::id()));
+    std::fs::create_dir_all(directory.join("billing")).unwrap();
+    std::fs::write(
+        directory.join("schema.prisma"),
+        "model User {\n  id Int @id\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        directory.join("billing/invoice.prisma"),
+        "model Invoice {\n  id Int @id\n}\n",
+    )
+    .unwrap();
+    std::fs::write(directory.join("README.md"), "# Schema\n").unwrap();
+    let read = source::read(&[directory.display().to_string()]);
+    std::fs::remove_dir_all(&directory).unwrap();
+    let names: Vec<String> = read
+        .unwrap()
+        .into_iter()
+        .map(|file| {
+            std::path::Path::new(&file.name)
+                .strip_prefix(&directory)
+                .unwrap()
+                .display()
+                .to_string()
+        })
+        .collect();
+    assert_eq!(names, ["billing/invoice.prisma", "schema.prisma"]);
+}
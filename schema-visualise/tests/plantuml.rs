@@ -0,0 +1,60 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+model User {
+  id      Int      @id
+  email   String   @unique
+  posts   Post[]
+  profile Profile?
+  groups  Group[]
+}
+
+model Profile {
+  id     Int  @id
+  user   User @relation(fields: [userId], references: [id])
+  userId Int  @unique
+}
+
+model Post {
+  id       Int   @id
+  author   User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+
+model Group {
+  id    Int    @id
+  users User[]
+}
+
+model Membership {
+  groupId Int
+  userId  Int
+
+  @@id([groupId, userId])
+}
+"#;
+
+#[test]
+fn renders_entities_with_keys_above_the_separator() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let diagram = visualiser.render_plantuml();
+
+    assert!(diagram.starts_with("@startuml\n"));
+    assert!(diagram.ends_with("@enduml\n"));
+    assert!(
+        diagram.contains("entity User {\n  *id : Int <<PK>>\n  --\n  email : String <<UK>>\n}\n")
+    );
+    assert!(diagram
+        .contains("entity Profile {\n  *id : Int <<PK>>\n  --\n  userId : Int <<FK>> <<UK>>\n}\n"));
+    assert!(diagram.contains("  *groupId : Int <<PK>>\n  *userId : Int <<PK>>\n  --\n}\n"));
+}
+
+#[test]
+fn renders_relation_multiplicity() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let diagram = visualiser.render_plantuml();
+
+    assert!(diagram.contains("Profile |o--|| User : user\n"));
+    assert!(diagram.contains("Post }o--o| User : author\n"));
+    assert!(diagram.contains("Group }o--o{ User : users\n"));
+}
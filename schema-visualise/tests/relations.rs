@@ -0,0 +1,64 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+model User {
+  id        Int      @id
+  posts     Post[]
+  profile   Profile?
+  tags      Tag[]
+  manager   User?    @relation("Management", fields: [managerId], references: [id])
+  managerId Int?
+  reports   User[]   @relation("Management")
+}
+
+model Profile {
+  id     Int  @id
+  user   User @relation(fields: [userId], references: [id])
+  userId Int  @unique
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+
+model Tag {
+  id    Int    @id
+  users User[]
+}
+"#;
+
+fn relation_summary(visualiser: &SchemaVisualiser, model: &str, field: &str) -> String {
+    visualiser
+        .models
+        .iter()
+        .find(|m| m.name == model)
+        .and_then(|m| m.fields.iter().find(|f| f.name == field))
+        .map(|f| f.relation_ships.summary())
+        .unwrap()
+}
+
+#[test]
+fn resolves_relationship_types() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    visualiser.parse();
+
+    assert_eq!(relation_summary(&visualiser, "User", "posts"), "1:n Post");
+    assert_eq!(relation_summary(&visualiser, "Post", "author"), "1:n User");
+    assert_eq!(
+        relation_summary(&visualiser, "User", "profile"),
+        "1:1? Profile"
+    );
+    assert_eq!(relation_summary(&visualiser, "Profile", "user"), "1:1 User");
+    assert_eq!(relation_summary(&visualiser, "User", "tags"), "m:n Tag");
+    assert_eq!(
+        relation_summary(&visualiser, "User", "manager"),
+        "self 1:n? User"
+    );
+    assert_eq!(
+        relation_summary(&visualiser, "User", "reports"),
+        "self 1:n User"
+    );
+    assert_eq!(relation_summary(&visualiser, "User", "id"), "");
+}
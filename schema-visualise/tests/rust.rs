@@ -0,0 +1,192 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+/// Access level
+enum Role {
+  USER
+  SUPER_ADMIN @map("admin")
+}
+
+model User {
+  id        Int                   @id
+  /// Login, unique per user
+  email     String                @unique
+  name      String?
+  roles     Role[]
+  balance   Decimal
+  big       BigInt?
+  meta      Json
+  avatar    Bytes?
+  createdAt DateTime              @map("created_at")
+  type      String
+  secret    String                @ignore
+  geo       Unsupported("point")?
+  posts     Post[]
+}
+
+model Post {
+  id       Int   @id
+  author   User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+
+model Legacy {
+  id Int
+
+  @@ignore
+}
+"#;
+
+#[test]
+fn models_and_enums() {
+    let rust = SchemaVisualiser::new(SCHEMA.to_string()).render_rust();
+    assert!(rust.starts_with("use serde::{Deserialize, Serialize};\n"));
+    assert!(rust.contains(
+        r#"/// Access level
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    #[serde(rename = "USER")]
+    User,
+    #[serde(rename = "admin")]
+    SuperAdmin,
+}"#
+    ));
+    assert!(rust.contains(
+        r#"#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    pub id: i32,
+    /// Login, unique per user
+    pub email: String,
+    pub name: Option<String>,
+    pub roles: Vec<Role>,
+    pub balance: rust_decimal::Decimal,
+    pub big: Option<i64>,
+    pub meta: serde_json::Value,
+    pub avatar: Option<Vec<u8>>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub r#type: String,
+}"#
+    ));
+    assert!(rust.contains(
+        "pub struct Post {\n    pub id: i32,\n    #[serde(rename = \"authorId\")]\n    pub author_id: Option<i32>,\n}"
+    ));
+    assert!(!rust.contains("Legacy"));
+}
+
+#[test]
+fn composite_types() {
+    let rust = SchemaVisualiser::new(
+        r#"
+datasource db {
+  provider = "mongodb"
+  url      = env("DATABASE_URL")
+}
+
+type Address {
+  street String
+  zip    String? @map("postcode")
+}
+
+model Customer {
+  id        String    @id @map("_id") @db.ObjectId
+  addresses Address[]
+}
+"#
+        .to_string(),
+    )
+    .render_rust();
+    assert!(rust.contains(
+        "pub struct Address {\n    pub street: String,\n    #[serde(rename = \"postcode\")]\n    pub zip: Option<String>,\n}"
+    ));
+    assert!(rust.contains("    #[serde(rename = \"_id\")]\n    pub id: String,\n"));
+    assert!(rust.contains("    pub addresses: Vec<Address>,\n"));
+}
+
+/// The struct or enum `name` as rendered from `schema`
+fn declaration(schema: &str, name: &str) -> String {
+    let rust = SchemaVisualiser::new(schema.to_string()).render_rust();
+    assert!(!rust.is_empty(), "{} does not validate", schema);
+    let start = rust
+        .find(&format!(" {} {{\n", name))
+        .unwrap_or_else(|| panic!("no {} in {}", name, rust));
+    let end = start + rust[start..].find("\n}\n").unwrap() + 1;
+    rust[start..end].to_string()
+}
+
+#[test]
+fn path_keywords_get_a_trailing_underscore() {
+    let model = declaration(
+        "model Path {\n  id Int @id\n  self Int\n  crate Int\n  super Int\n}\n",
+        "Path",
+    );
+    // `r#self`, `r#crate` and `r#super` are not valid raw identifiers
+    assert!(model.contains("    #[serde(rename = \"self\")]\n    pub self_: i32,\n"));
+    assert!(model.contains("    #[serde(rename = \"crate\")]\n    pub crate_: i32,\n"));
+    assert!(model.contains("    #[serde(rename = \"super\")]\n    pub super_: i32,\n"));
+    assert!(!model.contains("r#"));
+
+    let r#enum = declaration(
+        "enum Scope {\n  Self\n  Other\n}\n\nmodel M {\n  id Int @id\n  scope Scope\n}\n",
+        "Scope",
+    );
+    assert!(r#enum.contains("    #[serde(rename = \"Self\")]\n    Self_,\n    Other,"));
+}
+
+#[test]
+fn reserved_words_are_raw_identifiers() {
+    let reserved = [
+        "abstract", "become", "box", "do", "final", "macro", "override", "priv", "try", "typeof",
+        "unsized", "virtual", "yield",
+    ];
+    let fields: String = reserved
+        .iter()
+        .map(|word| format!("  {} Int\n", word))
+        .collect();
+    let model = declaration(
+        &format!("model Reserved {{\n  id Int @id\n{}}}\n", fields),
+        "Reserved",
+    );
+    reserved.iter().for_each(|word| {
+        assert!(
+            model.contains(&format!("    pub r#{}: i32,\n", word)),
+            "{}",
+            model
+        )
+    });
+    assert!(!model.contains("serde(rename"));
+}
+
+#[test]
+fn colliding_names_are_numbered() {
+    let model = declaration(
+        "model Membership {\n  id Int @id\n  userId Int\n  user_id Int\n  UserID Int\n}\n",
+        "Membership",
+    );
+    assert!(model.contains(
+        r#"    #[serde(rename = "userId")]
+    pub user_id: i32,
+    #[serde(rename = "user_id")]
+    pub user_id_2: i32,
+    #[serde(rename = "UserID")]
+    pub user_id_3: i32,"#
+    ));
+
+    let r#enum = declaration(
+        "enum Level {\n  LOW\n  low\n}\n\nmodel M {\n  id Int @id\n  level Level\n}\n",
+        "Level",
+    );
+    assert!(r#enum.contains(
+        "    #[serde(rename = \"LOW\")]\n    Low,\n    #[serde(rename = \"low\")]\n    Low2,\n"
+    ));
+}
+
+#[test]
+fn invalid_schema() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Nope @id\n}\n".to_string());
+    assert_eq!(visualiser.render_rust(), "");
+}
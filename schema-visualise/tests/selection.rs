@@ -0,0 +1,289 @@
+use prismaviz::{SchemaFile, SchemaVisualiser, Selection, SourceMap};
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+
+enum InvoiceStatus {
+  OPEN
+  PAID
+}
+
+model User {
+  id       Int            @id
+  role     Role
+  invoices BillingInvoice[]
+  /// Things the user wrote
+  posts    Post[]
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+
+model BillingInvoice {
+  id       Int              @id
+  status   InvoiceStatus
+  user     User             @relation(fields: [userId], references: [id])
+  userId   Int
+  payments BillingPayment[]
+}
+
+model BillingPayment {
+  id        Int            @id
+  invoice   BillingInvoice @relation(fields: [invoiceId], references: [id])
+  invoiceId Int
+}
+
+model AuditLog {
+  id Int @id
+}
+"#;
+
+fn selected(selection: Selection) -> SchemaVisualiser {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    visualiser.select(&selection);
+    visualiser
+}
+
+fn names(visualiser: &SchemaVisualiser) -> Vec<&str> {
+    visualiser
+        .models
+        .iter()
+        .map(|model| model.name.as_str())
+        .collect()
+}
+
+#[test]
+fn keeps_the_models_one_relation_away_by_default() {
+    let visualiser = selected(Selection {
+        models: vec!["BillingInvoice".to_string()],
+        ..Selection::default()
+    });
+    assert!(
+        !visualiser.has_errors(),
+        "{:?}",
+        visualiser.schema_diagnostics()
+    );
+    assert_eq!(
+        names(&visualiser),
+        [
+            "Role",
+            "InvoiceStatus",
+            "User",
+            "BillingInvoice",
+            "BillingPayment"
+        ]
+    );
+    // User's relation to the dropped Post goes, the schema itself stays as it was written
+    let user = &visualiser.models[2];
+    let fields: Vec<&str> = user
+        .fields
+        .iter()
+        .map(|field| field.name.as_str())
+        .collect();
+    assert_eq!(fields, ["id", "role", "invoices"]);
+    assert_eq!(visualiser.schema, SCHEMA);
+    assert_eq!(&SCHEMA[user.span.start..user.span.end], user.code);
+}
+
+#[test]
+fn walks_as_deep_as_asked() {
+    let selection = |depth| Selection {
+        models: vec!["BillingPayment".to_string()],
+        depth,
+        ..Selection::default()
+    };
+    assert_eq!(names(&selected(selection(0))), ["BillingPayment"]);
+    assert_eq!(
+        names(&selected(selection(1))),
+        ["InvoiceStatus", "BillingInvoice", "BillingPayment"]
+    );
+    assert_eq!(
+        names(&selected(selection(2))),
+        [
+            "Role",
+            "InvoiceStatus",
+            "User",
+            "BillingInvoice",
+            "BillingPayment"
+        ]
+    );
+}
+
+#[test]
+fn includes_and_excludes_by_pattern() {
+    let visualiser = selected(Selection {
+        include: vec!["Billing*".to_string()],
+        ..Selection::default()
+    });
+    assert_eq!(
+        names(&visualiser),
+        ["InvoiceStatus", "BillingInvoice", "BillingPayment"]
+    );
+    assert!(
+        !visualiser.has_errors(),
+        "{:?}",
+        visualiser.schema_diagnostics()
+    );
+
+    let visualiser = selected(Selection {
+        exclude: vec!["Billing*".to_string(), "A?ditLog".to_string()],
+        ..Selection::default()
+    });
+    assert_eq!(names(&visualiser), ["Role", "User", "Post"]);
+    assert!(
+        !visualiser.has_errors(),
+        "{:?}",
+        visualiser.schema_diagnostics()
+    );
+}
+
+#[test]
+fn walks_only_to_included_models() {
+    let visualiser = selected(Selection {
+        models: vec!["User".to_string()],
+        depth: 2,
+        include: vec!["Billing*".to_string()],
+        ..Selection::default()
+    });
+    assert_eq!(
+        names(&visualiser),
+        [
+            "Role",
+            "InvoiceStatus",
+            "User",
+            "BillingInvoice",
+            "BillingPayment"
+        ]
+    );
+}
+
+#[test]
+fn applies_to_every_renderer() {
+    let mut visualiser = selected(Selection {
+        models: vec!["Post".to_string()],
+        ..Selection::default()
+    });
+    let mermaid = visualiser.render_mermaid();
+    assert!(mermaid.contains("Post }o--|| User"));
+    assert!(!mermaid.contains("BillingInvoice"));
+    let sql = visualiser.render_sql(prismaviz::sql::Dialect::Postgres);
+    assert!(sql.contains("CREATE TABLE \"Post\""));
+    assert!(sql.contains("CREATE TYPE \"Role\""));
+    assert!(!sql.contains("AuditLog"));
+    assert!(!sql.contains("InvoiceStatus"));
+    assert!(!visualiser.render_docs().contains("BillingInvoice"));
+    assert!(!visualiser.render_dbml().contains("BillingInvoice"));
+    assert!(!visualiser.render_graphql().contains("BillingInvoice"));
+    assert!(!visualiser.render_rust().contains("BillingInvoice"));
+    let json_schema = visualiser.json_schema().unwrap();
+    let definitions: Vec<&String> = json_schema["$defs"].as_object().unwrap().keys().collect();
+    assert_eq!(definitions, ["User", "Post"]);
+}
+
+#[test]
+fn leaves_out_the_relations_to_dropped_models() {
+    let mut visualiser = selected(Selection {
+        models: vec!["BillingPayment".to_string()],
+        depth: 0,
+        ..Selection::default()
+    });
+    let sql = visualiser.render_sql(prismaviz::sql::Dialect::Postgres);
+    // The column stays, its foreign key to the dropped table goes
+    assert!(sql.contains("\"invoiceId\" INTEGER NOT NULL"));
+    assert!(!sql.contains("FOREIGN KEY"));
+    let ts = visualiser.render_typescript(&Default::default());
+    assert!(ts.contains("  invoiceId: number;\n"));
+    assert!(!ts.contains("invoice?"));
+    assert!(!visualiser.render_dbml().contains("Ref:"));
+    assert!(!visualiser.render_docs().contains("**Relations**"));
+}
+
+#[test]
+fn keeps_the_files_of_a_split_schema() {
+    let files = [
+        SchemaFile::new("user.prisma", "model User {\n  id    Int    @id\n  posts Post[]\n}"),
+        SchemaFile::new(
+            "post.prisma",
+            "model Post {\n  id       Int  @id\n  author   User @relation(fields: [authorId], references: [id])\n  authorId Int\n}\n",
+        ),
+        SchemaFile::new("audit.prisma", "model AuditLog {\n  id Int @id\n}\n"),
+    ];
+    let mut visualiser = SchemaVisualiser::from_files(&files);
+    visualiser.select(&Selection {
+        exclude: vec!["Post".to_string()],
+        ..Selection::default()
+    });
+    assert!(
+        !visualiser.has_errors(),
+        "{:?}",
+        visualiser.schema_diagnostics()
+    );
+    let kept_files: Vec<&str> = visualiser.files.files().map(|(name, _)| name).collect();
+    assert_eq!(kept_files, ["user.prisma", "post.prisma", "audit.prisma"]);
+    assert_eq!(visualiser.schema, SourceMap::concat(&files).0);
+    let models: Vec<(&str, Option<&str>)> = visualiser
+        .models
+        .iter()
+        .map(|model| (model.name.as_str(), model.file.as_deref()))
+        .collect();
+    assert_eq!(
+        models,
+        [
+            ("User", Some("user.prisma")),
+            ("AuditLog", Some("audit.prisma"))
+        ]
+    );
+}
+
+#[test]
+fn leaves_an_invalid_schema_alone() {
+    let schema = "model User {\n  id Foo @id\n}\n\nmodel Post {\n  id Int @id\n}\n";
+    let mut visualiser = SchemaVisualiser::new(schema.to_string());
+    visualiser.select(&Selection {
+        models: vec!["Post".to_string()],
+        ..Selection::default()
+    });
+    assert_eq!(names(&visualiser), ["User", "Post"]);
+}
+
+#[test]
+fn starts_only_from_models_and_views() {
+    let visualiser = selected(Selection {
+        models: vec!["Post".to_string(), "Role".to_string(), "Nope".to_string()],
+        ..Selection::default()
+    });
+    // Enums are in the model list too, but a selection can not start from them
+    assert!(names(&visualiser).contains(&"Role"));
+    assert_eq!(visualiser.unknown_models(), ["Role", "Nope"]);
+    assert!(selected(Selection {
+        models: vec!["Post".to_string()],
+        ..Selection::default()
+    })
+    .unknown_models()
+    .is_empty());
+}
+
+#[test]
+fn renders_without_parsing_again() {
+    let mut visualiser = selected(Selection {
+        models: vec!["AuditLog".to_string()],
+        depth: 0,
+        ..Selection::default()
+    });
+    // The parsed models are what the renderers show until the schema changes
+    visualiser.models.retain(|model| model.name != "AuditLog");
+    assert_eq!(visualiser.render_mermaid(), "erDiagram\n");
+    visualiser.schema.push_str("\n// Changed\n");
+    assert!(visualiser.render_mermaid().contains("AuditLog {"));
+}
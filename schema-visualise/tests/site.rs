@@ -0,0 +1,103 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+/// Someone who can log in
+model User {
+  id    Int    @id
+  email String @unique
+  posts Post[]
+}
+
+model Post {
+  id       Int  @id
+  author   User @relation(fields: [authorId], references: [id])
+  authorId Int
+}
+
+enum Role {
+  USER
+  ADMIN
+}
+"#;
+
+fn file<'a>(files: &'a [(&'static str, String)], path: &str) -> &'a str {
+    &files.iter().find(|(name, _)| *name == path).unwrap().1
+}
+
+/// `document` without the `id` of every model, which is new on every render
+fn without_ids(mut document: serde_json::Value) -> serde_json::Value {
+    document["result"]
+        .as_array_mut()
+        .unwrap()
+        .iter_mut()
+        .for_each(|model| {
+            model.as_object_mut().unwrap().remove("id").unwrap();
+        });
+    document
+}
+
+/// The object `data.js` assigns to `window.PRISMAVIZ`
+fn data(files: &[(&'static str, String)]) -> serde_json::Value {
+    let data_js = file(files, "data.js");
+    let json = data_js
+        .strip_prefix("window.PRISMAVIZ = ")
+        .and_then(|rest| rest.strip_suffix(";\n"))
+        .unwrap();
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn writes_a_page_loading_its_assets_relatively() {
+    let files = SchemaVisualiser::new(SCHEMA.to_string()).render_site();
+    let paths: Vec<&str> = files.iter().map(|(path, _)| *path).collect();
+    assert_eq!(
+        paths,
+        [
+            "index.html",
+            "style.css",
+            "app.js",
+            "data.js",
+            "visualise.json"
+        ]
+    );
+    let index = file(&files, "index.html");
+    assert!(index.contains(r#"<link rel="stylesheet" href="style.css" />"#));
+    assert!(index.contains(r#"<script src="data.js"></script>"#));
+    assert!(index.contains(r#"<script src="app.js"></script>"#));
+    assert!(file(&files, "style.css").contains(".token-keyword"));
+}
+
+#[test]
+fn embeds_the_visualise_document() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    let files = visualiser.render_site();
+    let visualise: serde_json::Value =
+        serde_json::from_str(file(&files, "visualise.json")).unwrap();
+    assert_eq!(
+        without_ids(visualise.clone()),
+        without_ids(serde_json::from_str(&visualiser.render_json()).unwrap())
+    );
+    assert_eq!(data(&files)["visualise"], visualise);
+}
+
+#[test]
+fn highlights_the_code_of_every_declaration() {
+    let files = SchemaVisualiser::new(SCHEMA.to_string()).render_site();
+    let code = data(&files)["code"].clone();
+    let names: Vec<&String> = code.as_object().unwrap().keys().collect();
+    assert_eq!(names, ["User", "Post", "Role"]);
+    let user = code["User"].as_str().unwrap();
+    assert!(user.starts_with(r#"<pre class="schema-code">"#));
+    assert!(user.contains(
+        r#"<span class="token-keyword">model</span> <span class="token-identifier">User</span>"#
+    ));
+    assert!(!user.contains("Post {"));
+}
+
+#[test]
+fn keeps_the_diagnostics_of_an_invalid_schema() {
+    let files = SchemaVisualiser::new("model User {\n  id Foo @id\n}\n".to_string()).render_site();
+    let diagnostics = data(&files)["visualise"]["diagnostics"].clone();
+    assert_eq!(diagnostics[0]["severity"], "error");
+    assert!(diagnostics[0]["message"].as_str().unwrap().contains("Foo"));
+}
@@ -0,0 +1,131 @@
+use prismaviz::{sql::Dialect, SchemaVisualiser};
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+enum Role {
+  USER
+  ADMIN @map("admin")
+}
+
+model User {
+  id     Int     @id @default(autoincrement())
+  email  String  @unique @db.VarChar(200)
+  name   String?
+  role   Role    @default(USER)
+  posts  Post[]
+  groups Group[]
+
+  @@map("users")
+}
+
+model Post {
+  id       String @id
+  title    String @default("It's new")
+  authorId Int
+  author   User   @relation(fields: [authorId], references: [id], onDelete: Cascade)
+
+  @@index([title(sort: Desc), authorId])
+}
+
+model Group {
+  id    Int    @id
+  users User[]
+}
+"#;
+
+fn render(dialect: Dialect) -> String {
+    SchemaVisualiser::new(SCHEMA.to_string()).render_sql(dialect)
+}
+
+#[test]
+fn postgres() {
+    let sql = render(Dialect::Postgres);
+    assert!(sql.contains(r#"CREATE TYPE "Role" AS ENUM ('USER', 'admin');"#));
+    assert!(sql.contains(
+        r#"CREATE TABLE "users" (
+    "id" SERIAL NOT NULL,
+    "email" VARCHAR(200) NOT NULL,
+    "name" TEXT,
+    "role" "Role" NOT NULL DEFAULT 'USER',
+
+    CONSTRAINT "users_pkey" PRIMARY KEY ("id")
+);"#
+    ));
+    assert!(sql.contains(r#""title" TEXT NOT NULL DEFAULT 'It''s new',"#));
+    assert!(sql.contains(r#"CREATE UNIQUE INDEX "users_email_key" ON "users"("email");"#));
+    assert!(sql.contains(
+        r#"CREATE INDEX "Post_title_authorId_idx" ON "Post"("title" DESC, "authorId");"#
+    ));
+    assert!(sql.contains(r#"ALTER TABLE "Post" ADD CONSTRAINT "Post_authorId_fkey" FOREIGN KEY ("authorId") REFERENCES "users"("id") ON DELETE CASCADE ON UPDATE CASCADE;"#));
+}
+
+#[test]
+fn implicit_many_to_many() {
+    let sql = render(Dialect::Postgres);
+    assert!(sql.contains(
+        r#"CREATE TABLE "_GroupToUser" (
+    "A" INTEGER NOT NULL,
+    "B" INTEGER NOT NULL
+);"#
+    ));
+    assert!(sql
+        .contains(r#"CREATE UNIQUE INDEX "_GroupToUser_AB_unique" ON "_GroupToUser"("A", "B");"#));
+    assert!(sql.contains(r#"CREATE INDEX "_GroupToUser_B_index" ON "_GroupToUser"("B");"#));
+    assert!(sql.contains(
+        r#"ADD CONSTRAINT "_GroupToUser_B_fkey" FOREIGN KEY ("B") REFERENCES "users"("id")"#
+    ));
+}
+
+#[test]
+fn mysql() {
+    let sql = render(Dialect::Mysql);
+    assert!(!sql.contains("CREATE TYPE"));
+    assert!(sql.contains("`id` INT NOT NULL AUTO_INCREMENT,"));
+    assert!(sql.contains("`role` ENUM('USER', 'admin') NOT NULL DEFAULT 'USER',"));
+    assert!(sql.contains(") DEFAULT CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci;"));
+}
+
+#[test]
+fn sqlserver() {
+    let sql = render(Dialect::Sqlserver);
+    assert!(sql.contains("[id] INT NOT NULL IDENTITY(1,1),"));
+    assert!(
+        sql.contains("[role] NVARCHAR(1000) NOT NULL CONSTRAINT [users_role_df] DEFAULT 'USER',")
+    );
+    assert!(sql.contains("CONSTRAINT [users_pkey] PRIMARY KEY CLUSTERED ([id])"));
+    assert!(sql
+        .contains("CREATE UNIQUE NONCLUSTERED INDEX [users_email_key] ON [dbo].[users]([email]);"));
+}
+
+#[test]
+fn sqlite_declares_foreign_keys_inline() {
+    let sql = render(Dialect::Sqlite);
+    assert!(sql.contains(r#""id" INTEGER NOT NULL PRIMARY KEY AUTOINCREMENT,"#));
+    assert!(sql.contains(r#"    PRIMARY KEY ("id"),
+    CONSTRAINT "Post_authorId_fkey" FOREIGN KEY ("authorId") REFERENCES "users"("id") ON DELETE CASCADE ON UPDATE CASCADE
+);"#));
+    assert!(!sql.contains("ALTER TABLE"));
+}
+
+#[test]
+fn relation_mode_prisma_has_no_foreign_keys() {
+    let schema = SCHEMA.replace(
+        r#"url      = env("DATABASE_URL")"#,
+        r#"url      = env("DATABASE_URL")
+  relationMode = "prisma""#,
+    );
+    let sql = SchemaVisualiser::new(schema).render_sql(Dialect::Postgres);
+    assert!(sql.contains("CREATE TABLE"));
+    assert!(!sql.contains("FOREIGN KEY"));
+}
+
+#[test]
+fn invalid_schema() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Nope @id\n}\n".to_string());
+    assert_eq!(visualiser.render_sql(Dialect::Postgres), "");
+    assert!(visualiser.has_errors());
+}
@@ -0,0 +1,174 @@
+use prismaviz::SchemaVisualiser;
+
+const SCHEMA: &str = r#"
+model User {
+  id      Int      @id
+  email   String   @unique
+  posts   Post[]
+  profile Profile?
+}
+
+model Profile {
+  id     Int  @id
+  user   User @relation(fields: [userId], references: [id])
+  userId Int  @unique
+}
+
+model Post {
+  id       Int   @id
+  author   User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+
+/// Reports to a manager, and may mentor or be mentored
+model Employee {
+  id          Int        @id
+  managerId   Int?
+  manager     Employee?  @relation("Management", fields: [managerId], references: [id])
+  reports     Employee[] @relation("Management")
+  mentorId    Int?
+  mentor      Mentor?    @relation(fields: [mentorId], references: [id])
+  mentoring   Mentor?    @relation("Mentoring")
+}
+
+model Mentor {
+  id         Int        @id
+  employeeId Int        @unique
+  employee   Employee   @relation("Mentoring", fields: [employeeId], references: [id])
+  mentees    Employee[]
+}
+"#;
+
+/// `x`, `y`, `width` and `height` of the box of `model`
+fn model_box(svg: &str, model: &str) -> [i32; 4] {
+    let group = svg
+        .split(&format!("<g class=\"model\" id=\"model-{}\">", model))
+        .nth(1)
+        .unwrap();
+    let rect = group.split("<rect class=\"box\" ").nth(1).unwrap();
+    let attribute = |name: &str| -> i32 {
+        rect.split(&format!("{}=\"", name))
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .unwrap()
+            .parse()
+            .unwrap()
+    };
+    [
+        attribute("x"),
+        attribute("y"),
+        attribute("width"),
+        attribute("height"),
+    ]
+}
+
+fn render() -> String {
+    SchemaVisualiser::new(SCHEMA.to_string()).render_svg()
+}
+
+#[test]
+fn renders_one_box_per_model_with_field_rows() {
+    let svg = render();
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    ["User", "Profile", "Post", "Employee", "Mentor"]
+        .iter()
+        .for_each(|model| assert!(svg.contains(&format!("id=\"model-{}\"", model))));
+    assert!(svg.contains(">email</text>"));
+    assert!(svg.contains(">String</text>"));
+    assert!(svg.contains(">FK, UK</text>"));
+    assert!(!svg.contains(">posts</text>"));
+}
+
+#[test]
+fn places_referenced_models_right_of_the_referencing_ones() {
+    let svg = render();
+    let user = model_box(&svg, "User");
+    let post = model_box(&svg, "Post");
+    let profile = model_box(&svg, "Profile");
+    assert!(post[0] + post[2] < user[0]);
+    assert!(profile[0] + profile[2] < user[0]);
+}
+
+#[test]
+fn boxes_do_not_overlap() {
+    let svg = render();
+    let boxes: Vec<[i32; 4]> = ["User", "Profile", "Post", "Employee", "Mentor"]
+        .iter()
+        .map(|model| model_box(&svg, model))
+        .collect();
+    boxes.iter().enumerate().for_each(|(i, a)| {
+        boxes.iter().skip(i + 1).for_each(|b| {
+            let apart = a[0] + a[2] <= b[0]
+                || b[0] + b[2] <= a[0]
+                || a[1] + a[3] <= b[1]
+                || b[1] + b[3] <= a[1];
+            assert!(apart, "{:?} overlaps {:?}", a, b);
+        })
+    });
+}
+
+#[test]
+fn routes_relations_orthogonally() {
+    let svg = render();
+    let paths: Vec<&str> = svg
+        .split("<path d=\"")
+        .skip(1)
+        .map(|rest| rest.split('"').next().unwrap())
+        .collect();
+    // user, author, manager, mentor and employee
+    assert_eq!(paths.len(), 5);
+    paths.iter().for_each(|path| {
+        let commands: Vec<&str> = path
+            .split(' ')
+            .filter(|part| part.parse::<i32>().is_err())
+            .collect();
+        assert_eq!(commands, ["M", "H", "V", "H"]);
+    });
+    assert!(svg.contains("<title>Employee.manager</title>"));
+    assert!(svg.contains("text-anchor=\"end\">0..1</text>"));
+}
+
+#[test]
+fn parallel_relations_get_lanes_of_their_own() {
+    let count = 20;
+    let (forward, back): (String, String) = (0..count)
+        .map(|i| {
+            (
+                format!(
+                    "  b{i}   B @relation(\"r{i}\", fields: [b{i}Id], references: [id])\n  b{i}Id Int\n"
+                ),
+                format!("  a{i} A[] @relation(\"r{i}\")\n"),
+            )
+        })
+        .unzip();
+    let svg = SchemaVisualiser::new(format!(
+        "model A {{\n  id Int @id\n{forward}}}\n\nmodel B {{\n  id Int @id\n{back}}}\n"
+    ))
+    .render_svg();
+    let (a, b) = (model_box(&svg, "A"), model_box(&svg, "B"));
+    let mut lanes: Vec<i32> = svg
+        .split("<path d=\"")
+        .skip(1)
+        .map(|rest| {
+            // `M x y H lane V y H x`
+            let path = rest.split('"').next().unwrap();
+            path.split(' ').nth(4).unwrap().parse().unwrap()
+        })
+        .collect();
+    assert_eq!(lanes.len(), count);
+    assert!(lanes.iter().all(|lane| a[0] + a[2] < *lane && *lane < b[0]));
+    lanes.sort();
+    assert!(
+        lanes.windows(2).all(|pair| pair[1] - pair[0] >= 10),
+        "parallel relations are drawn on top of each other: {:?}",
+        lanes
+    );
+}
+
+#[test]
+fn empty_schema() {
+    let svg = SchemaVisualiser::new(String::new()).render_svg();
+    assert!(svg.starts_with("<svg"));
+    assert!(!svg.contains("<g class=\"model\""));
+}
@@ -0,0 +1,136 @@
+use prismaviz::{typescript::TypescriptOptions, SchemaVisualiser};
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+generator client {
+  provider        = "prisma-client-js"
+  previewFeatures = ["views"]
+}
+
+/// Access level
+enum Role {
+  USER
+  ADMIN @map("admin")
+}
+
+model User {
+  id      Int                   @id
+  /// Login, unique per user
+  email   String                @unique
+  name    String?
+  roles   Role[]
+  balance Decimal
+  big     BigInt?
+  meta    Json
+  avatar  Bytes?
+  created DateTime
+  secret  String                @ignore
+  geo     Unsupported("point")?
+  posts   Post[]
+}
+
+model Post {
+  id       Int   @id
+  author   User? @relation(fields: [authorId], references: [id])
+  authorId Int?
+}
+
+model Legacy {
+  id Int
+
+  @@ignore
+}
+
+view Author {
+  id    Int    @unique
+  email String
+}
+"#;
+
+fn render(options: &TypescriptOptions) -> String {
+    SchemaVisualiser::new(SCHEMA.to_string()).render_typescript(options)
+}
+
+#[test]
+fn models_and_enums() {
+    let ts = render(&TypescriptOptions::default());
+    assert!(ts.contains(
+        r#"/**
+ * Access level
+ */
+export type Role = "USER" | "ADMIN";"#
+    ));
+    assert!(ts.contains(
+        r#"export interface User {
+  id: number;
+  /**
+   * Login, unique per user
+   */
+  email: string;
+  name: string | null;
+  roles: Role[];
+  balance: string;
+  big: bigint | null;
+  meta: unknown;
+  avatar: Uint8Array | null;
+  created: Date;
+  posts?: Post[];
+}"#
+    ));
+    assert!(ts.contains("  author?: User | null;\n  authorId: number | null;\n"));
+    assert!(ts.contains("export interface Author {\n  id: number;\n  email: string;\n}"));
+    assert!(!ts.contains("Legacy"));
+}
+
+#[test]
+fn configurable_scalars() {
+    let ts = render(&TypescriptOptions {
+        decimal: "Decimal".to_string(),
+        bigint: "string".to_string(),
+        json: "JsonValue".to_string(),
+        bytes: "Buffer".to_string(),
+    });
+    assert!(ts.contains("  balance: Decimal;\n"));
+    assert!(ts.contains("  big: string | null;\n"));
+    assert!(ts.contains("  meta: JsonValue;\n"));
+    assert!(ts.contains("  avatar: Buffer | null;\n"));
+}
+
+#[test]
+fn composite_types() {
+    let ts = SchemaVisualiser::new(
+        r#"
+datasource db {
+  provider = "mongodb"
+  url      = env("DATABASE_URL")
+}
+
+type Address {
+  street String
+  zip    String?
+}
+
+model Customer {
+  id        String    @id @map("_id") @db.ObjectId
+  addresses Address[]
+}
+"#
+        .to_string(),
+    )
+    .render_typescript(&TypescriptOptions::default());
+    assert!(ts.contains("export interface Address {\n  street: string;\n  zip: string | null;\n}"));
+    assert!(ts.contains("  addresses: Address[];\n"));
+}
+
+#[test]
+fn invalid_schema() {
+    let mut visualiser = SchemaVisualiser::new("model User {\n  id Nope @id\n}\n".to_string());
+    assert_eq!(
+        visualiser.render_typescript(&TypescriptOptions::default()),
+        ""
+    );
+}
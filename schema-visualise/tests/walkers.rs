@@ -0,0 +1,75 @@
+use prismaviz::{PrismaVizModelField, SchemaVisualiser};
+
+const SCHEMA: &str = r#"
+datasource db {
+  provider = "postgresql"
+  url      = env("DATABASE_URL")
+}
+
+enum Role {
+  ADMIN @map("admin")
+  USER
+}
+
+model Account {
+  tenant   String
+  number   Int
+  email    String  @unique @db.VarChar(200)
+  nickname String? @map("nick_name")
+  role     Role    @default(USER)
+  region   String
+
+  @@id(name: "account_pk", fields: [tenant, number])
+  @@unique(fields: [region, nickname], name: "region_nick")
+  @@index([region])
+  @@map("accounts")
+}
+"#;
+
+fn field<'a>(
+    visualiser: &'a SchemaVisualiser,
+    model: &str,
+    field: &str,
+) -> &'a PrismaVizModelField {
+    visualiser
+        .models
+        .iter()
+        .find(|m| m.name == model)
+        .and_then(|m| m.fields.iter().find(|f| f.name == field))
+        .unwrap()
+}
+
+#[test]
+fn reads_compound_keys_with_named_arguments() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    visualiser.parse();
+
+    assert!(field(&visualiser, "Account", "tenant").is_id());
+    assert!(field(&visualiser, "Account", "number").is_id());
+    // Only unique together with `region`
+    assert!(!field(&visualiser, "Account", "nickname").is_unique());
+    assert_eq!(field(&visualiser, "Account", "nickname").is_index, "true");
+    assert!(field(&visualiser, "Account", "email").is_unique());
+    assert_eq!(field(&visualiser, "Account", "region").is_index, "true");
+    assert_eq!(field(&visualiser, "Account", "email").is_index, "false");
+}
+
+#[test]
+fn reads_arity_database_names_native_types_and_defaults() {
+    let mut visualiser = SchemaVisualiser::new(SCHEMA.to_string());
+    visualiser.parse();
+
+    assert_eq!(visualiser.models[1].database_name, "accounts");
+
+    let nickname = field(&visualiser, "Account", "nickname");
+    assert_eq!(nickname.r#type.resolve_with_modifier(), "String?");
+    assert_eq!(nickname.database_name, "nick_name");
+
+    let email = field(&visualiser, "Account", "email");
+    assert_eq!(email.native_type.as_deref(), Some("db.VarChar(200)"));
+
+    let role = field(&visualiser, "Account", "role");
+    assert_eq!(role.default.as_deref(), Some("USER"));
+
+    assert_eq!(field(&visualiser, "Role", "ADMIN").database_name, "admin");
+}